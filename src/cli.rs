@@ -1,9 +1,12 @@
-use clap::Parser;
+use clap::{Args, Parser, Subcommand, ValueEnum};
 
 #[derive(Parser, Debug)]
 #[command(name = "ccline")]
 #[command(version, about = "High-performance Claude Code StatusLine")]
 pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
     /// Enter TUI configuration mode
     #[arg(short = 'c', long = "config")]
     pub config: bool,
@@ -12,6 +15,10 @@ pub struct Cli {
     #[arg(short = 't', long = "theme")]
     pub theme: Option<String>,
 
+    /// Print collected segment data as JSON instead of the rendered statusline
+    #[arg(long = "json")]
+    pub json: bool,
+
     /// Print current configuration
     #[arg(long = "print")]
     pub print: bool,
@@ -33,6 +40,72 @@ pub struct Cli {
     pub patch: Option<String>,
 }
 
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Aggregate token usage and cost across all Claude Code projects
+    Report(ReportArgs),
+    /// Re-render the statusline from saved inputs, e.g. ones captured by the recorder
+    Render(RenderArgs),
+}
+
+#[derive(Args, Debug)]
+pub struct ReportArgs {
+    /// Group rows by period, project or model
+    #[arg(short = 'b', long = "by", value_enum, default_value_t = ReportGroup::Day)]
+    pub by: ReportGroup,
+
+    /// Output format
+    #[arg(short = 'f', long = "format", value_enum, default_value_t = ReportFormat::Table)]
+    pub format: ReportFormat,
+
+    /// Only include messages on or after this local date (YYYY-MM-DD)
+    #[arg(long = "since")]
+    pub since: Option<String>,
+}
+
+#[derive(Args, Debug)]
+pub struct RenderArgs {
+    /// File with a statusline input JSON, or a log of inputs one per line
+    #[arg(short = 'i', long = "input", required_unless_present = "replay")]
+    pub input: Option<String>,
+
+    /// Re-render the inputs captured by the recorder (~/.claude/ccline/inputs.jsonl)
+    #[arg(long = "replay", conflicts_with = "input")]
+    pub replay: bool,
+
+    /// Only render the newest N inputs
+    #[arg(short = 'n', long = "last")]
+    pub last: Option<usize>,
+
+    /// Render with this theme instead of the current configuration
+    #[arg(short = 't', long = "theme")]
+    pub theme: Option<String>,
+
+    /// Render with the configuration in this file
+    #[arg(long = "config", conflicts_with = "theme")]
+    pub config: Option<String>,
+
+    /// Print collected segment data as JSON instead of the rendered statusline
+    #[arg(long = "json")]
+    pub json: bool,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq)]
+pub enum ReportGroup {
+    Day,
+    Week,
+    Month,
+    Project,
+    Model,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq)]
+pub enum ReportFormat {
+    Table,
+    Json,
+    Csv,
+}
+
 impl Cli {
     pub fn parse_args() -> Self {
         Self::parse()
@@ -0,0 +1,155 @@
+use super::InputData;
+use regex::Regex;
+use serde::de::{DeserializeOwned, Deserializer};
+use serde::Deserialize;
+use serde_json::Value;
+use std::io::Write;
+use std::path::PathBuf;
+
+/// Start a new log once the current one grows past this size
+const MAX_LOG_BYTES: u64 = 256 * 1024;
+
+/// String fields recovered from input that isn't valid JSON, as (key, JSON pointer)
+const SALVAGED_FIELDS: [(&str, &str); 10] = [
+    ("id", "/model/id"),
+    ("display_name", "/model/display_name"),
+    ("current_dir", "/workspace/current_dir"),
+    ("project_dir", "/workspace/project_dir"),
+    ("transcript_path", "/transcript_path"),
+    ("session_id", "/session_id"),
+    ("version", "/version"),
+    ("cwd", "/cwd"),
+    ("hook_event_name", "/hook_event_name"),
+    ("name", "/output_style/name"),
+];
+
+/// Deserialize a field of any type, falling back to its default when the value has
+/// an unexpected type. Strings holding a JSON value ("12.5", "true") are read as that
+/// value, and whole floats are accepted for integers.
+pub(crate) fn lenient<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: DeserializeOwned + Default,
+{
+    let value = Value::deserialize(deserializer)?;
+    if let Ok(parsed) = serde_json::from_value(value.clone()) {
+        return Ok(parsed);
+    }
+
+    let converted = match &value {
+        Value::String(text) => serde_json::from_str(text).ok(),
+        Value::Number(number) => number
+            .as_f64()
+            .filter(|n| n.fract() == 0.0)
+            .and_then(|n| serde_json::from_value(Value::from(n as i64)).ok()),
+        _ => None,
+    };
+    Ok(converted.unwrap_or_default())
+}
+
+/// Deserialize a string field, accepting numbers and booleans as their text
+pub(crate) fn lenient_string<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(scalar_text(Value::deserialize(deserializer)?).unwrap_or_default())
+}
+
+/// Deserialize an optional string field, accepting numbers and booleans as their text
+pub(crate) fn lenient_opt_string<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(scalar_text(Value::deserialize(deserializer)?))
+}
+
+fn scalar_text(value: Value) -> Option<String> {
+    match value {
+        Value::String(text) => Some(text),
+        Value::Number(number) => Some(number.to_string()),
+        Value::Bool(flag) => Some(flag.to_string()),
+        _ => None,
+    }
+}
+
+impl InputData {
+    /// Parse Claude Code's statusline input. Fields missing or of an unexpected type
+    /// fall back to defaults; only input that isn't a JSON object is an error.
+    pub fn parse(raw: &str) -> Result<Self, serde_json::Error> {
+        let mut input: InputData = serde_json::from_str(raw)?;
+        if input.workspace.current_dir.is_empty() {
+            if let Some(cwd) = &input.cwd {
+                input.workspace.current_dir = cwd.clone();
+            }
+        }
+        Ok(input)
+    }
+
+    /// Parse input, salvaging what it can when parsing fails; the error is returned
+    /// alongside so the statusline can be marked as degraded
+    pub fn parse_or_salvage(raw: &str) -> (Self, Option<serde_json::Error>) {
+        match Self::parse(raw) {
+            Ok(input) => (input, None),
+            Err(error) => (Self::salvage(raw), Some(error)),
+        }
+    }
+
+    /// Best-effort input from text that failed to parse, e.g. truncated JSON:
+    /// known string fields are picked out wherever they appear
+    pub fn salvage(raw: &str) -> Self {
+        let mut value = serde_json::json!({ "model": {}, "workspace": {}, "output_style": {} });
+
+        for (key, pointer) in SALVAGED_FIELDS {
+            let pattern = format!(r#""{}"\s*:\s*"((?:[^"\\]|\\.)*)""#, key);
+            let text = Regex::new(&pattern)
+                .ok()
+                .and_then(|re| re.captures(raw))
+                .and_then(|caps| serde_json::from_str::<String>(&format!("\"{}\"", &caps[1])).ok());
+            let Some(text) = text else { continue };
+
+            // An empty parent pointer is the root object
+            let (parent, field) = pointer.rsplit_once('/').unwrap_or(("", pointer));
+            if let Some(Value::Object(object)) = value.pointer_mut(parent) {
+                object.insert(field.to_string(), Value::String(text));
+            }
+        }
+
+        Self::parse(&value.to_string()).unwrap_or_default()
+    }
+
+    pub fn get_error_log_path() -> Option<PathBuf> {
+        let home = dirs::home_dir()?;
+        Some(home.join(".claude").join("ccline").join("input_errors.log"))
+    }
+
+    /// Append input that failed to parse to the error log, for debugging schema changes
+    pub fn log_parse_error(raw: &str, error: &serde_json::Error) {
+        let Some(path) = Self::get_error_log_path() else {
+            return;
+        };
+        if let Some(parent) = path.parent() {
+            let _ = std::fs::create_dir_all(parent);
+        }
+
+        let oversized = std::fs::metadata(&path)
+            .map(|meta| meta.len() > MAX_LOG_BYTES)
+            .unwrap_or(false);
+        let file = std::fs::OpenOptions::new()
+            .create(true)
+            .write(true)
+            .append(!oversized)
+            .truncate(oversized)
+            .open(&path);
+
+        if let Ok(mut file) = file {
+            let _ = writeln!(
+                file,
+                "=== {} ccline {}: {}\n{}",
+                chrono::Local::now().to_rfc3339(),
+                env!("CARGO_PKG_VERSION"),
+                error,
+                raw.trim_end()
+            );
+        }
+    }
+}
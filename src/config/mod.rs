@@ -1,4 +1,5 @@
 pub mod defaults;
+mod input;
 pub mod loader;
 pub mod models;
 pub mod types;
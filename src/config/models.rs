@@ -1,3 +1,4 @@
+use super::NormalizedUsage;
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::Path;
@@ -8,11 +9,68 @@ pub struct ModelConfig {
     pub model_entries: Vec<ModelEntry>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ModelEntry {
     pub pattern: String,
+    /// Empty display name keeps Claude Code's own display_name
+    #[serde(default)]
     pub display_name: String,
-    pub context_limit: u32,
+    /// Unset entries (e.g. pricing only) leave the limit to later matches
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub context_limit: Option<u32>,
+    // Pricing in USD per million tokens
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub input_price: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output_price: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cache_write_price: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cache_read_price: Option<f64>,
+    /// Tokens Claude Code keeps free before autocompacting
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub autocompact_buffer: Option<u32>,
+}
+
+/// Context limit used when no model entry sets one
+pub const DEFAULT_CONTEXT_LIMIT: u32 = 200_000;
+
+/// Autocompact buffer used when no model entry sets one
+pub const DEFAULT_AUTOCOMPACT_BUFFER: u32 = 45_000;
+
+/// Resolved per-million-token prices for a model
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ModelPricing {
+    pub input: f64,
+    pub output: f64,
+    pub cache_write: f64,
+    pub cache_read: f64,
+}
+
+impl ModelPricing {
+    /// Cost in USD for a single usage record
+    pub fn cost(&self, usage: &NormalizedUsage) -> f64 {
+        (usage.input_tokens as f64 * self.input
+            + usage.output_tokens as f64 * self.output
+            + usage.cache_creation_input_tokens as f64 * self.cache_write
+            + usage.cache_read_input_tokens as f64 * self.cache_read)
+            / 1_000_000.0
+    }
+}
+
+impl ModelEntry {
+    /// Pricing is available once input and output prices are set
+    /// Cache prices default to the input price when omitted
+    pub fn pricing(&self) -> Option<ModelPricing> {
+        let input = self.input_price?;
+        let output = self.output_price?;
+        Some(ModelPricing {
+            input,
+            output,
+            cache_write: self.cache_write_price.unwrap_or(input),
+            cache_read: self.cache_read_price.unwrap_or(input),
+        })
+    }
 }
 
 impl ModelConfig {
@@ -58,18 +116,27 @@ impl ModelConfig {
     }
 
     /// Get context limit for a model based on ID pattern matching
-    /// Checks external config first, then falls back to built-in config
+    /// Uses the first matching entry that sets one, checking external config first
     pub fn get_context_limit(&self, model_id: &str) -> u32 {
         let model_lower = model_id.to_lowercase();
 
-        // Check model entries
-        for entry in &self.model_entries {
-            if model_lower.contains(&entry.pattern.to_lowercase()) {
-                return entry.context_limit;
-            }
-        }
+        self.model_entries
+            .iter()
+            .filter(|entry| model_lower.contains(&entry.pattern.to_lowercase()))
+            .find_map(|entry| entry.context_limit)
+            .unwrap_or(DEFAULT_CONTEXT_LIMIT)
+    }
 
-        200_000
+    /// Get the autocompact buffer for a model based on ID pattern matching
+    /// Uses the first matching entry that sets one, otherwise the default
+    pub fn get_autocompact_buffer(&self, model_id: &str) -> u32 {
+        let model_lower = model_id.to_lowercase();
+
+        self.model_entries
+            .iter()
+            .filter(|entry| model_lower.contains(&entry.pattern.to_lowercase()))
+            .find_map(|entry| entry.autocompact_buffer)
+            .unwrap_or(DEFAULT_AUTOCOMPACT_BUFFER)
     }
 
     /// Get display name for a model based on ID pattern matching
@@ -80,7 +147,9 @@ impl ModelConfig {
 
         // Check model entries
         for entry in &self.model_entries {
-            if model_lower.contains(&entry.pattern.to_lowercase()) {
+            if !entry.display_name.is_empty()
+                && model_lower.contains(&entry.pattern.to_lowercase())
+            {
                 return Some(entry.display_name.clone());
             }
         }
@@ -88,6 +157,17 @@ impl ModelConfig {
         None
     }
 
+    /// Get pricing for a model based on ID pattern matching
+    /// Uses the first matching entry that defines prices
+    pub fn get_pricing(&self, model_id: &str) -> Option<ModelPricing> {
+        let model_lower = model_id.to_lowercase();
+
+        self.model_entries
+            .iter()
+            .filter(|entry| model_lower.contains(&entry.pattern.to_lowercase()))
+            .find_map(|entry| entry.pricing())
+    }
+
     /// Create default model configuration file with minimal template
     pub fn create_default_file<P: AsRef<Path>>(path: P) -> Result<(), Box<dyn std::error::Error>> {
         // Create a minimal template config (not the full fallback config)
@@ -113,7 +193,14 @@ impl ModelConfig {
              # [[models]]\n\
              # pattern = \"glm-4.5\"\n\
              # display_name = \"GLM-4.5\"\n\
-             # context_limit = 128000\n",
+             # context_limit = 128000\n\
+             # Optional pricing in USD per million tokens (used for transcript-based cost)\n\
+             # input_price = 0.6\n\
+             # output_price = 2.2\n\
+             # cache_write_price = 0.6\n\
+             # cache_read_price = 0.11\n\
+             # Optional tokens kept free before Claude Code autocompacts (default 45000)\n\
+             # autocompact_buffer = 45000\n",
             toml_content.trim()
         );
 
@@ -135,7 +222,8 @@ impl Default for ModelConfig {
                 ModelEntry {
                     pattern: "[1m]".to_string(),
                     display_name: "Sonnet 4.5 1M".to_string(),
-                    context_limit: 1_000_000,
+                    context_limit: Some(1_000_000),
+                    ..Default::default()
                 },
                 // ModelEntry {
                 //     pattern: "claude-sonnet-4-5".to_string(),
@@ -165,28 +253,118 @@ impl Default for ModelConfig {
                 ModelEntry {
                     pattern: "claude-3-7-sonnet".to_string(),
                     display_name: "Sonnet 3.7".to_string(),
-                    context_limit: 200_000,
+                    context_limit: Some(200_000),
+                    input_price: Some(3.0),
+                    output_price: Some(15.0),
+                    cache_write_price: Some(3.75),
+                    cache_read_price: Some(0.3),
+                    ..Default::default()
+                },
+                // Anthropic pricing (no display_name, Claude Code's own name is kept)
+                ModelEntry {
+                    pattern: "claude-opus-4-5".to_string(), // Opus 4.5
+                    input_price: Some(5.0),
+                    output_price: Some(25.0),
+                    cache_write_price: Some(6.25),
+                    cache_read_price: Some(0.5),
+                    ..Default::default()
+                },
+                ModelEntry {
+                    pattern: "claude-opus-4-1".to_string(), // Opus 4.1
+                    input_price: Some(15.0),
+                    output_price: Some(75.0),
+                    cache_write_price: Some(18.75),
+                    cache_read_price: Some(1.5),
+                    ..Default::default()
+                },
+                ModelEntry {
+                    pattern: "claude-opus-4-2025".to_string(), // Opus 4
+                    input_price: Some(15.0),
+                    output_price: Some(75.0),
+                    cache_write_price: Some(18.75),
+                    cache_read_price: Some(1.5),
+                    ..Default::default()
+                },
+                ModelEntry {
+                    pattern: "claude-opus-4@".to_string(), // Opus 4 on Vertex AI
+                    input_price: Some(15.0),
+                    output_price: Some(75.0),
+                    cache_write_price: Some(18.75),
+                    cache_read_price: Some(1.5),
+                    ..Default::default()
+                },
+                ModelEntry {
+                    pattern: "claude-3-opus".to_string(), // Opus 3
+                    input_price: Some(15.0),
+                    output_price: Some(75.0),
+                    cache_write_price: Some(18.75),
+                    cache_read_price: Some(1.5),
+                    ..Default::default()
+                },
+                ModelEntry {
+                    pattern: "claude-haiku-4-5".to_string(), // Haiku 4.5
+                    input_price: Some(1.0),
+                    output_price: Some(5.0),
+                    cache_write_price: Some(1.25),
+                    cache_read_price: Some(0.1),
+                    ..Default::default()
+                },
+                ModelEntry {
+                    pattern: "claude-3-5-haiku".to_string(), // Haiku 3.5
+                    input_price: Some(0.8),
+                    output_price: Some(4.0),
+                    cache_write_price: Some(1.0),
+                    cache_read_price: Some(0.08),
+                    ..Default::default()
+                },
+                ModelEntry {
+                    pattern: "claude-3-haiku".to_string(), // Haiku 3
+                    input_price: Some(0.25),
+                    output_price: Some(1.25),
+                    cache_write_price: Some(0.3),
+                    cache_read_price: Some(0.03),
+                    ..Default::default()
+                },
+                ModelEntry {
+                    pattern: "claude-sonnet-4".to_string(), // Sonnet 4 / 4.5
+                    input_price: Some(3.0),
+                    output_price: Some(15.0),
+                    cache_write_price: Some(3.75),
+                    cache_read_price: Some(0.3),
+                    ..Default::default()
+                },
+                ModelEntry {
+                    pattern: "claude-3-5-sonnet".to_string(), // Sonnet 3.5
+                    input_price: Some(3.0),
+                    output_price: Some(15.0),
+                    cache_write_price: Some(3.75),
+                    cache_read_price: Some(0.3),
+                    ..Default::default()
                 },
                 // Third-party models
                 ModelEntry {
                     pattern: "glm-4.5".to_string(),
                     display_name: "GLM-4.5".to_string(),
-                    context_limit: 128_000,
+                    context_limit: Some(128_000),
+                    ..Default::default()
                 },
                 ModelEntry {
                     pattern: "kimi-k2-turbo".to_string(),
                     display_name: "Kimi K2 Turbo".to_string(),
-                    context_limit: 128_000,
+                    context_limit: Some(128_000),
+                    ..Default::default()
                 },
                 ModelEntry {
                     pattern: "kimi-k2".to_string(),
                     display_name: "Kimi K2".to_string(),
-                    context_limit: 128_000,
+                    context_limit: Some(128_000),
+                    ..Default::default()
                 },
                 ModelEntry {
                     pattern: "qwen3-coder".to_string(),
                     display_name: "Qwen Coder".to_string(),
-                    context_limit: 256_000,
+                    context_limit: Some(256_000),
+                    ..Default::default()
                 },
             ],
         }
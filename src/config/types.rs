@@ -7,6 +7,10 @@ pub struct Config {
     pub style: StyleConfig,
     pub segments: Vec<SegmentConfig>,
     pub theme: String,
+    #[serde(default)]
+    pub time: TimeConfig,
+    #[serde(default)]
+    pub recorder: RecorderConfig,
 }
 
 // Default implementation moved to ui/themes/presets.rs
@@ -25,6 +29,60 @@ pub enum StyleMode {
     Powerline,
 }
 
+// Global date/time display preferences shared by all time-aware segments
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TimeConfig {
+    #[serde(default)]
+    pub hour_format: HourFormat,
+    #[serde(default = "default_time_locale")]
+    pub locale: String,
+}
+
+impl Default for TimeConfig {
+    fn default() -> Self {
+        Self {
+            hour_format: HourFormat::default(),
+            locale: default_time_locale(),
+        }
+    }
+}
+
+fn default_time_locale() -> String {
+    "en_US".to_string()
+}
+
+// Opt-in log of received statusline inputs, for reproducing rendering bugs with `ccline render`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecorderConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    /// Inputs kept in the log; older ones are dropped
+    #[serde(default = "default_recorder_max_entries")]
+    pub max_entries: usize,
+}
+
+impl Default for RecorderConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_entries: default_recorder_max_entries(),
+        }
+    }
+}
+
+fn default_recorder_max_entries() -> usize {
+    200
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum HourFormat {
+    #[default]
+    #[serde(rename = "12h")]
+    TwelveHour,
+    #[serde(rename = "24h")]
+    TwentyFourHour,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SegmentConfig {
     pub id: SegmentId,
@@ -75,6 +133,19 @@ pub enum SegmentId {
     Session,
     OutputStyle,
     Update,
+    Time,
+    BurnRate,
+    Spend,
+    CacheHit,
+    Compaction,
+    ToolActivity,
+    Todos,
+    Subagents,
+    FilesTouched,
+    TurnStats,
+    Thinking,
+    ClaudeVersion,
+    SessionId,
 }
 
 // Legacy compatibility structure
@@ -87,38 +158,133 @@ pub struct SegmentsConfig {
 }
 
 // Data structures compatible with existing main.rs
-#[derive(Deserialize)]
+// Mirrors the JSON Claude Code sends on stdin; fields added by newer versions are optional.
+// Every field tolerates absence and type drift so a schema change can't blank the statusline.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
 pub struct Model {
+    #[serde(default, deserialize_with = "super::input::lenient_string")]
     pub id: String,
+    #[serde(default, deserialize_with = "super::input::lenient_string")]
     pub display_name: String,
 }
 
-#[derive(Deserialize)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
 pub struct Workspace {
+    #[serde(default, deserialize_with = "super::input::lenient_string")]
     pub current_dir: String,
+    /// Directory Claude Code was started in
+    #[serde(
+        default,
+        deserialize_with = "super::input::lenient_opt_string",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub project_dir: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
-#[derive(Deserialize)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
 pub struct Cost {
+    #[serde(default, deserialize_with = "super::input::lenient")]
     pub total_cost_usd: Option<f64>,
+    #[serde(default, deserialize_with = "super::input::lenient")]
     pub total_duration_ms: Option<u64>,
+    #[serde(default, deserialize_with = "super::input::lenient")]
     pub total_api_duration_ms: Option<u64>,
+    #[serde(default, deserialize_with = "super::input::lenient")]
     pub total_lines_added: Option<u32>,
+    #[serde(default, deserialize_with = "super::input::lenient")]
     pub total_lines_removed: Option<u32>,
 }
 
-#[derive(Deserialize)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
 pub struct OutputStyle {
+    #[serde(default, deserialize_with = "super::input::lenient_string")]
     pub name: String,
 }
 
-#[derive(Deserialize)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
 pub struct InputData {
+    #[serde(default, deserialize_with = "super::input::lenient")]
     pub model: Model,
+    #[serde(default, deserialize_with = "super::input::lenient")]
     pub workspace: Workspace,
+    #[serde(default, deserialize_with = "super::input::lenient_string")]
     pub transcript_path: String,
+    #[serde(default, deserialize_with = "super::input::lenient")]
     pub cost: Option<Cost>,
+    #[serde(default, deserialize_with = "super::input::lenient")]
     pub output_style: Option<OutputStyle>,
+    #[serde(
+        default,
+        deserialize_with = "super::input::lenient_opt_string",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub session_id: Option<String>,
+    /// Claude Code version
+    #[serde(
+        default,
+        deserialize_with = "super::input::lenient_opt_string",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub version: Option<String>,
+    #[serde(
+        default,
+        deserialize_with = "super::input::lenient_opt_string",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub cwd: Option<String>,
+    /// Set by Claude Code once the context passes 200k tokens
+    #[serde(
+        default,
+        deserialize_with = "super::input::lenient",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub exceeds_200k_tokens: Option<bool>,
+    #[serde(
+        default,
+        deserialize_with = "super::input::lenient_opt_string",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub hook_event_name: Option<String>,
+    /// Fields this version doesn't know yet, kept so segments and templates can still read them
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+impl InputData {
+    /// Look up an input field by dotted path, e.g. "session_id" or "workspace.project_dir"
+    /// Works for passthrough fields too; strings are returned as is, other values as JSON
+    pub fn field(&self, path: &str) -> Option<String> {
+        let value = serde_json::to_value(self).ok()?;
+        let pointer = format!("/{}", path.replace('.', "/"));
+        match value.pointer(&pointer)? {
+            serde_json::Value::Null => None,
+            serde_json::Value::String(text) => Some(text.clone()),
+            other => Some(other.to_string()),
+        }
+    }
+
+    /// Replace `{input.<path>}` placeholders in a template with input fields
+    /// Missing fields render as "-"
+    pub fn render_template(&self, template: &str) -> String {
+        const PREFIX: &str = "{input.";
+
+        let mut output = String::new();
+        let mut rest = template;
+        while let Some(start) = rest.find(PREFIX) {
+            let after = &rest[start + PREFIX.len()..];
+            let end = match after.find('}') {
+                Some(end) => end,
+                None => break,
+            };
+            output.push_str(&rest[..start]);
+            output.push_str(&self.field(&after[..end]).unwrap_or_else(|| "-".to_string()));
+            rest = &after[end + 1..];
+        }
+        output.push_str(rest);
+        output
+    }
 }
 
 // OpenAI-style nested token details
@@ -184,7 +350,7 @@ pub struct RawUsage {
 }
 
 // Normalized internal representation after processing
-#[derive(Debug, Clone, Serialize, Default, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
 pub struct NormalizedUsage {
     pub input_tokens: u32,
     pub output_tokens: u32,
@@ -208,6 +374,19 @@ impl NormalizedUsage {
             + self.output_tokens
     }
 
+    /// Get context tokens without this turn's output
+    /// Falls back to display_tokens when no input breakdown is available
+    pub fn context_tokens_without_output(&self) -> u32 {
+        let input = self.input_tokens
+            + self.cache_creation_input_tokens
+            + self.cache_read_input_tokens;
+        if input > 0 {
+            input
+        } else {
+            self.display_tokens()
+        }
+    }
+
     /// Get total tokens for cost calculation
     /// Priority: use total_tokens if available, otherwise sum all components
     pub fn total_for_cost(&self) -> u32 {
@@ -399,20 +578,3 @@ impl RawUsage {
 
 // Legacy alias for backward compatibility
 pub type Usage = RawUsage;
-
-#[derive(Deserialize)]
-pub struct Message {
-    pub usage: Option<Usage>,
-}
-
-#[derive(Deserialize)]
-pub struct TranscriptEntry {
-    pub r#type: Option<String>,
-    pub message: Option<Message>,
-    #[serde(rename = "leafUuid")]
-    pub leaf_uuid: Option<String>,
-    pub uuid: Option<String>,
-    #[serde(rename = "parentUuid")]
-    pub parent_uuid: Option<String>,
-    pub summary: Option<String>,
-}
@@ -0,0 +1,137 @@
+use crate::config::ModelConfig;
+use crate::core::spend::{self, UsageRecord};
+use chrono::{DateTime, Duration, DurationRound, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Length of a Claude billing block
+pub const BLOCK_HOURS: i64 = 5;
+
+/// How far back to scan first; doubled while activity runs up to the start of the scan
+const LOOKBACK_HOURS: i64 = 24;
+
+/// Longest scan; a run of back-to-back blocks longer than this may start misaligned
+const MAX_LOOKBACK_HOURS: i64 = 24 * 7;
+
+/// A 5-hour billing block reconstructed from local transcript timestamps
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BillingBlock {
+    /// Start of the block, floored to the hour of its first message
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    pub first_message: DateTime<Utc>,
+    pub last_message: DateTime<Utc>,
+    pub messages: u64,
+    pub tokens: u64,
+    pub cost_usd: f64,
+    pub unpriced_messages: u64,
+}
+
+impl BillingBlock {
+    fn open(record: &UsageRecord) -> Self {
+        let start = record
+            .timestamp
+            .duration_trunc(Duration::hours(1))
+            .unwrap_or(record.timestamp);
+        let mut block = Self {
+            start,
+            end: start + Duration::hours(BLOCK_HOURS),
+            first_message: record.timestamp,
+            last_message: record.timestamp,
+            ..Default::default()
+        };
+        block.add(record);
+        block
+    }
+
+    fn add(&mut self, record: &UsageRecord) {
+        self.last_message = record.timestamp;
+        self.messages += 1;
+        self.tokens += record.usage.total_for_cost() as u64;
+        match record.cost_usd {
+            Some(cost) => self.cost_usd += cost,
+            None => self.unpriced_messages += 1,
+        }
+    }
+
+    /// Whether the block is still running at `now`
+    pub fn is_active(&self, now: DateTime<Utc>) -> bool {
+        now < self.end && now - self.last_message < Duration::hours(BLOCK_HOURS)
+    }
+
+    /// Time left until the block ends, zero once it has ended
+    pub fn remaining(&self, now: DateTime<Utc>) -> Duration {
+        (self.end - now).max(Duration::zero())
+    }
+
+    /// Share of the block that has elapsed at `now`, from 0 to 100
+    pub fn elapsed_percent(&self, now: DateTime<Utc>) -> f64 {
+        let total = Duration::hours(BLOCK_HOURS).num_seconds() as f64;
+        let elapsed = (now - self.start).num_seconds() as f64;
+        (elapsed / total * 100.0).clamp(0.0, 100.0)
+    }
+
+    /// Extrapolate tokens and cost to the end of the block at the current rate
+    /// The rate is measured from the first message, with a minimum of one minute
+    pub fn projected(&self, now: DateTime<Utc>) -> (u64, f64) {
+        let elapsed_minutes = ((now - self.first_message).num_seconds() as f64 / 60.0).max(1.0);
+        let remaining_minutes = self.remaining(now).num_seconds() as f64 / 60.0;
+        let scale = 1.0 + remaining_minutes / elapsed_minutes;
+
+        (
+            (self.tokens as f64 * scale).round() as u64,
+            self.cost_usd * scale,
+        )
+    }
+}
+
+/// Group records into billing blocks
+/// A new block starts when a message falls past the current block's end,
+/// or comes after a gap of a full block length since the previous message
+pub fn identify_blocks(records: &[UsageRecord]) -> Vec<BillingBlock> {
+    let mut sorted: Vec<&UsageRecord> = records.iter().collect();
+    sorted.sort_by_key(|record| record.timestamp);
+
+    let mut blocks: Vec<BillingBlock> = Vec::new();
+    for record in sorted {
+        match blocks.last_mut() {
+            Some(block)
+                if record.timestamp < block.end
+                    && record.timestamp - block.last_message < Duration::hours(BLOCK_HOURS) =>
+            {
+                block.add(record);
+            }
+            _ => blocks.push(BillingBlock::open(record)),
+        }
+    }
+
+    blocks
+}
+
+/// Reconstruct the block active at `now` from all project transcripts
+///
+/// Block starts depend on every earlier block of a run, so a scan that begins inside
+/// a run would misalign them; the scan reaches further back until it starts in a gap.
+pub fn current_block(now: DateTime<Utc>) -> Option<BillingBlock> {
+    let projects_dir = spend::claude_projects_dir()?;
+    let model_config = ModelConfig::load();
+
+    let mut lookback_hours = LOOKBACK_HOURS;
+    loop {
+        let since = now - Duration::hours(lookback_hours);
+        let records = spend::collect_usage_records(&projects_dir, Some(since), &model_config);
+        let blocks = identify_blocks(&records);
+        // A block can only be active with a message less than a block length ago
+        let last = blocks
+            .last()
+            .filter(|block| now - block.last_message < Duration::hours(BLOCK_HOURS))?;
+
+        // A first message within a block length of the scan start may continue earlier activity
+        let starts_in_gap = blocks
+            .first()
+            .is_some_and(|block| block.first_message - since >= Duration::hours(BLOCK_HOURS));
+        if starts_in_gap || lookback_hours >= MAX_LOOKBACK_HOURS {
+            return Some(last.clone()).filter(|block| block.is_active(now));
+        }
+        lookback_hours = (lookback_hours * 2).min(MAX_LOOKBACK_HOURS);
+    }
+}
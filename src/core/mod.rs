@@ -1,4 +1,8 @@
+pub mod blocks;
+pub mod recorder;
 pub mod segments;
+pub mod spend;
 pub mod statusline;
+pub mod transcript;
 
 pub use statusline::{collect_all_segments, StatusLineGenerator};
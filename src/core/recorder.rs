@@ -0,0 +1,144 @@
+use crate::config::{InputData, RecorderConfig};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// One statusline input as received, with the time it arrived
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Recording {
+    /// RFC 3339 time the input was received; empty for inputs not made by the recorder
+    #[serde(default)]
+    pub recorded_at: String,
+    /// Input as received, when it was valid JSON
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub input: Option<Value>,
+    /// Input text that isn't valid JSON, e.g. truncated
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub raw: Option<String>,
+}
+
+impl Recording {
+    fn from_raw(raw: &str, recorded_at: String) -> Self {
+        match serde_json::from_str::<Value>(raw) {
+            Ok(input) => Self {
+                recorded_at,
+                input: Some(input),
+                raw: None,
+            },
+            Err(_) => Self {
+                recorded_at,
+                input: None,
+                raw: Some(raw.to_string()),
+            },
+        }
+    }
+
+    /// The recorded input, parsed the same way as live input: malformed input is
+    /// salvaged and returned with its parse error
+    pub fn input_data(&self) -> (InputData, Option<serde_json::Error>) {
+        match (&self.input, &self.raw) {
+            (Some(input), _) => InputData::parse_or_salvage(&input.to_string()),
+            (None, Some(raw)) => InputData::parse_or_salvage(raw),
+            (None, None) => InputData::parse_or_salvage(""),
+        }
+    }
+}
+
+/// Path of the rolling input log (~/.claude/ccline/inputs.jsonl)
+pub fn get_log_path() -> Option<PathBuf> {
+    let home = dirs::home_dir()?;
+    Some(home.join(".claude").join("ccline").join("inputs.jsonl"))
+}
+
+/// Append raw input to the log when recording is enabled, keeping the newest
+/// `max_entries`. Failures are ignored so recording never breaks the statusline.
+pub fn record(raw: &str, config: &RecorderConfig) {
+    if !config.enabled || config.max_entries == 0 {
+        return;
+    }
+    let Some(path) = get_log_path() else {
+        return;
+    };
+    if let Some(parent) = path.parent() {
+        let _ = fs::create_dir_all(parent);
+    }
+
+    let recording = Recording::from_raw(raw.trim(), chrono::Local::now().to_rfc3339());
+    let Ok(line) = serde_json::to_string(&recording) else {
+        return;
+    };
+
+    // A single appended line can't interleave with other sessions writing at the same time
+    let appended = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .and_then(|mut file| writeln!(file, "{}", line));
+    if appended.is_ok() {
+        trim_log(&path, config.max_entries);
+    }
+}
+
+/// Drop the oldest entries beyond `max_entries`, replacing the file by rename
+/// Entries appended by another session between the read and the rename are lost,
+/// which is acceptable for a debugging log.
+fn trim_log(path: &Path, max_entries: usize) {
+    let Ok(content) = fs::read_to_string(path) else {
+        return;
+    };
+    let lines: Vec<&str> = content.lines().filter(|line| !line.is_empty()).collect();
+    if lines.len() <= max_entries {
+        return;
+    }
+
+    let kept = lines[lines.len() - max_entries..].join("\n") + "\n";
+    // A temp file per process, so sessions trimming at the same time don't share one
+    let temp_path = path.with_extension(format!("jsonl.{}.tmp", std::process::id()));
+    if fs::write(&temp_path, kept).is_ok() && fs::rename(&temp_path, path).is_err() {
+        let _ = fs::remove_file(&temp_path);
+    }
+}
+
+/// Read inputs from a file: a single statusline input JSON, a single recording,
+/// or a log of either, one per line. A file with none of these, e.g. a truncated
+/// input, is read as one malformed input so it can still be rendered degraded.
+pub fn load(path: &Path) -> Result<Vec<Recording>, Box<dyn std::error::Error>> {
+    let content = fs::read_to_string(path)?;
+    let content = content.trim();
+    if content.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    // A pretty-printed input spans several lines, so try the whole file first
+    if let Some(recording) = parse_recording(content) {
+        return Ok(vec![recording]);
+    }
+
+    let recordings: Vec<Recording> = content.lines().filter_map(parse_recording).collect();
+    if recordings.is_empty() {
+        return Ok(vec![Recording::from_raw(content, String::new())]);
+    }
+    Ok(recordings)
+}
+
+/// A recording line, or a statusline input JSON object
+fn parse_recording(text: &str) -> Option<Recording> {
+    let value: Value = serde_json::from_str(text.trim()).ok()?;
+    let object = value.as_object()?;
+
+    if object.contains_key("recorded_at")
+        && (object.contains_key("input") || object.contains_key("raw"))
+    {
+        if let Ok(recording) = serde_json::from_value::<Recording>(value.clone()) {
+            return Some(recording);
+        }
+    }
+
+    Some(Recording {
+        recorded_at: String::new(),
+        input: Some(value),
+        raw: None,
+    })
+}
@@ -0,0 +1,166 @@
+use super::threshold_utils::Thresholds;
+use super::{color_utils, Segment, SegmentData};
+use crate::config::{InputData, SegmentId};
+use crate::core::transcript::{self, MessageDeduper};
+use chrono::{DateTime, Duration, Utc};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Token throughput over the sliding window
+#[derive(Debug, Default)]
+struct BurnRate {
+    input_tokens: u64,
+    output_tokens: u64,
+    elapsed_minutes: f64,
+}
+
+impl BurnRate {
+    fn total_tokens(&self) -> u64 {
+        self.input_tokens + self.output_tokens
+    }
+
+    fn per_minute(&self, tokens: u64) -> f64 {
+        tokens as f64 / self.elapsed_minutes
+    }
+}
+
+pub struct BurnRateSegment {
+    window_minutes: i64,
+    include_cache_reads: bool,
+    thresholds: Thresholds,
+}
+
+impl Default for BurnRateSegment {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BurnRateSegment {
+    pub fn new() -> Self {
+        Self {
+            window_minutes: 10,
+            include_cache_reads: false,
+            thresholds: Thresholds::default(),
+        }
+    }
+
+    /// Warning and critical settings from the segment's options
+    pub fn with_thresholds(mut self, thresholds: Thresholds) -> Self {
+        self.thresholds = thresholds;
+        self
+    }
+
+    pub fn with_window_minutes(mut self, window_minutes: u64) -> Self {
+        self.window_minutes = window_minutes.max(1) as i64;
+        self
+    }
+
+    pub fn with_cache_reads(mut self, include_cache_reads: bool) -> Self {
+        self.include_cache_reads = include_cache_reads;
+        self
+    }
+
+    fn format_rate(rate: f64) -> String {
+        if rate >= 1000.0 {
+            let k_value = rate / 1000.0;
+            if k_value >= 10.0 {
+                format!("{:.0}k", k_value)
+            } else {
+                format!("{:.1}k", k_value)
+            }
+        } else {
+            format!("{:.0}", rate)
+        }
+    }
+
+    /// Sum assistant usage whose timestamp falls in the window ending now, reading the
+    /// transcript from its end so only the window is parsed
+    /// Subagent (sidechain) messages are left out: newer Claude Code versions write them to
+    /// separate transcripts, so counting them here would depend on the version.
+    fn compute_burn_rate(&self, path: &Path, now: DateTime<Utc>) -> Option<BurnRate> {
+        let window_start = now - Duration::minutes(self.window_minutes);
+        let mut earliest: Option<DateTime<Utc>> = None;
+        let mut deduper = MessageDeduper::new();
+        let mut rate = BurnRate::default();
+
+        for entry in transcript::entries_rev(path).ok()? {
+            let timestamp = match entry.time() {
+                Some(timestamp) => timestamp,
+                None => continue,
+            };
+            // Everything before this is older than the window
+            if timestamp < window_start {
+                earliest = Some(window_start);
+                break;
+            }
+            earliest = Some(timestamp);
+
+            if !entry.is_assistant() || entry.is_sidechain || !deduper.first_seen(&entry) {
+                continue;
+            }
+
+            if let Some(usage) = entry.usage() {
+                let mut input = usage.input_tokens + usage.cache_creation_input_tokens;
+                if self.include_cache_reads {
+                    input += usage.cache_read_input_tokens;
+                }
+                rate.input_tokens += input as u64;
+                rate.output_tokens += usage.output_tokens as u64;
+            }
+        }
+
+        // Young sessions are measured from their first entry instead of the full window
+        let start = earliest?;
+        let elapsed = now.signed_duration_since(start).num_seconds() as f64 / 60.0;
+        rate.elapsed_minutes = elapsed.max(1.0);
+
+        Some(rate)
+    }
+}
+
+impl Segment for BurnRateSegment {
+    fn collect(&self, input: &InputData) -> Option<SegmentData> {
+        let rate = self.compute_burn_rate(Path::new(&input.transcript_path), Utc::now())?;
+
+        let total_rate = rate.per_minute(rate.total_tokens());
+        let input_rate = rate.per_minute(rate.input_tokens);
+        let output_rate = rate.per_minute(rate.output_tokens);
+
+        let primary = format!("{}/min", Self::format_rate(total_rate));
+        let secondary = format!(
+            "↑{} ↓{}",
+            Self::format_rate(input_rate),
+            Self::format_rate(output_rate)
+        );
+
+        let mut metadata = HashMap::new();
+        metadata.insert("tokens_per_minute".to_string(), total_rate.to_string());
+        metadata.insert("input_per_minute".to_string(), input_rate.to_string());
+        metadata.insert("output_per_minute".to_string(), output_rate.to_string());
+        metadata.insert("window_tokens".to_string(), rate.total_tokens().to_string());
+        metadata.insert("window_minutes".to_string(), self.window_minutes.to_string());
+
+        // Check if we need to apply threshold-based color override
+        if let Some(color) = self.thresholds.color_for_utilization(total_rate) {
+            // Serialize the color to JSON for metadata using shared helper
+            let color_json = color_utils::serialize_ansi_color_to_json(&color);
+            metadata.insert("text_color_override".to_string(), color_json);
+        }
+
+        // Check if we need to apply threshold-based bold override
+        if let Some(should_bold) = self.thresholds.bold_for_utilization(total_rate) {
+            metadata.insert("text_bold_override".to_string(), should_bold.to_string());
+        }
+
+        Some(SegmentData {
+            primary,
+            secondary,
+            metadata,
+        })
+    }
+
+    fn id(&self) -> SegmentId {
+        SegmentId::BurnRate
+    }
+}
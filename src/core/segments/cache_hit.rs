@@ -0,0 +1,189 @@
+use super::threshold_utils::Thresholds;
+use super::{color_utils, CostSegment, Segment, SegmentData};
+use crate::config::{InputData, ModelConfig, NormalizedUsage, SegmentId};
+use crate::core::transcript::{self, MessageDeduper};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Cache reads against total input for a set of turns
+#[derive(Debug, Default, Clone, Copy)]
+struct CacheStats {
+    input_tokens: u64,
+    cache_creation_tokens: u64,
+    cache_read_tokens: u64,
+    /// Net dollars saved by caching: reads billed below the input price, minus the
+    /// premium paid to write the cache; negative while writes outweigh reads
+    saved_usd: f64,
+}
+
+impl CacheStats {
+    fn add(&mut self, usage: &NormalizedUsage, model_config: &ModelConfig, model: &str) {
+        self.input_tokens += usage.input_tokens as u64;
+        self.cache_creation_tokens += usage.cache_creation_input_tokens as u64;
+        self.cache_read_tokens += usage.cache_read_input_tokens as u64;
+        if let Some(pricing) = model_config.get_pricing(model) {
+            let read_savings =
+                usage.cache_read_input_tokens as f64 * (pricing.input - pricing.cache_read);
+            let write_premium =
+                usage.cache_creation_input_tokens as f64 * (pricing.cache_write - pricing.input);
+            self.saved_usd += (read_savings - write_premium) / 1_000_000.0;
+        }
+    }
+
+    fn total_input(&self) -> u64 {
+        self.input_tokens + self.cache_creation_tokens + self.cache_read_tokens
+    }
+
+    /// Percentage of input served from the prompt cache
+    fn hit_ratio(&self) -> Option<f64> {
+        let total = self.total_input();
+        if total == 0 {
+            return None;
+        }
+        Some(self.cache_read_tokens as f64 / total as f64 * 100.0)
+    }
+}
+
+pub struct CacheHitSegment {
+    show_session: bool,
+    show_savings: bool,
+    thresholds: Thresholds,
+}
+
+impl Default for CacheHitSegment {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CacheHitSegment {
+    pub fn new() -> Self {
+        Self {
+            show_session: true,
+            show_savings: true,
+            thresholds: Thresholds::default(),
+        }
+    }
+
+    /// Warning and critical settings from the segment's options
+    pub fn with_thresholds(mut self, thresholds: Thresholds) -> Self {
+        self.thresholds = thresholds;
+        self
+    }
+
+    pub fn with_session(mut self, show_session: bool) -> Self {
+        self.show_session = show_session;
+        self
+    }
+
+    pub fn with_savings(mut self, show_savings: bool) -> Self {
+        self.show_savings = show_savings;
+        self
+    }
+
+    /// Collect cache stats for the last assistant turn and the whole session
+    fn collect_stats(path: &Path, default_model: &str) -> Option<(CacheStats, CacheStats)> {
+        let model_config = ModelConfig::load();
+
+        let mut deduper = MessageDeduper::new();
+        let mut last_turn: Option<CacheStats> = None;
+        let mut session = CacheStats::default();
+
+        for entry in transcript::entries(path).ok()? {
+            if !entry.is_assistant() || !deduper.first_seen(&entry) {
+                continue;
+            }
+
+            let usage = match entry.usage() {
+                Some(usage) => usage,
+                None => continue,
+            };
+
+            let model = entry.model().unwrap_or(default_model);
+            session.add(&usage, &model_config, model);
+
+            // Subagents keep their own cache, so the last turn follows the main thread
+            if !entry.is_sidechain {
+                let mut turn = CacheStats::default();
+                turn.add(&usage, &model_config, model);
+                last_turn = Some(turn);
+            }
+        }
+
+        Some((last_turn?, session))
+    }
+}
+
+impl Segment for CacheHitSegment {
+    fn collect(&self, input: &InputData) -> Option<SegmentData> {
+        let (last_turn, session) =
+            Self::collect_stats(Path::new(&input.transcript_path), &input.model.id)?;
+
+        let last_ratio = last_turn.hit_ratio()?;
+        let session_ratio = session.hit_ratio().unwrap_or(0.0);
+
+        let primary = format!("{:.0}%", last_ratio);
+
+        let mut details = Vec::new();
+        if self.show_session {
+            details.push(format!("session {:.0}%", session_ratio));
+        }
+        if self.show_savings && session.saved_usd > 0.0 {
+            details.push(format!(
+                "saved {}",
+                CostSegment::format_cost(session.saved_usd)
+            ));
+        }
+        let secondary = if details.is_empty() {
+            String::new()
+        } else {
+            format!("· {}", details.join(" · "))
+        };
+
+        let mut metadata = HashMap::new();
+        metadata.insert("last_turn_hit_ratio".to_string(), last_ratio.to_string());
+        metadata.insert("session_hit_ratio".to_string(), session_ratio.to_string());
+        metadata.insert(
+            "session_cache_read_tokens".to_string(),
+            session.cache_read_tokens.to_string(),
+        );
+        metadata.insert(
+            "session_cache_creation_tokens".to_string(),
+            session.cache_creation_tokens.to_string(),
+        );
+        metadata.insert(
+            "session_input_tokens".to_string(),
+            session.input_tokens.to_string(),
+        );
+        metadata.insert(
+            "tokens_saved".to_string(),
+            session.cache_read_tokens.to_string(),
+        );
+        metadata.insert("saved_usd".to_string(), session.saved_usd.to_string());
+
+        // Thresholds apply to the miss rate, so a busted cache turns the segment red
+        let miss_rate = 100.0 - last_ratio;
+
+        // Check if we need to apply threshold-based color override
+        if let Some(color) = self.thresholds.color_for_utilization(miss_rate) {
+            // Serialize the color to JSON for metadata using shared helper
+            let color_json = color_utils::serialize_ansi_color_to_json(&color);
+            metadata.insert("text_color_override".to_string(), color_json);
+        }
+
+        // Check if we need to apply threshold-based bold override
+        if let Some(should_bold) = self.thresholds.bold_for_utilization(miss_rate) {
+            metadata.insert("text_bold_override".to_string(), should_bold.to_string());
+        }
+
+        Some(SegmentData {
+            primary,
+            secondary,
+            metadata,
+        })
+    }
+
+    fn id(&self) -> SegmentId {
+        SegmentId::CacheHit
+    }
+}
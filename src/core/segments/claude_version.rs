@@ -0,0 +1,48 @@
+use super::{Segment, SegmentData};
+use crate::config::{InputData, SegmentId};
+use crate::core::transcript;
+use std::collections::HashMap;
+
+#[derive(Default)]
+pub struct ClaudeVersionSegment;
+
+impl ClaudeVersionSegment {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Version recorded on the newest transcript entry, for Claude Code versions
+    /// that don't send it in the statusline input
+    fn transcript_version(transcript_path: &str) -> Option<String> {
+        transcript::entries_rev(transcript_path)
+            .ok()?
+            .take(50)
+            .find_map(|entry| entry.version)
+    }
+}
+
+impl Segment for ClaudeVersionSegment {
+    fn collect(&self, input: &InputData) -> Option<SegmentData> {
+        let (version, source) = match &input.version {
+            Some(version) => (version.clone(), "input"),
+            None => (
+                Self::transcript_version(&input.transcript_path)?,
+                "transcript",
+            ),
+        };
+
+        let mut metadata = HashMap::new();
+        metadata.insert("version".to_string(), version.clone());
+        metadata.insert("source".to_string(), source.to_string());
+
+        Some(SegmentData {
+            primary: format!("v{}", version),
+            secondary: String::new(),
+            metadata,
+        })
+    }
+
+    fn id(&self) -> SegmentId {
+        SegmentId::ClaudeVersion
+    }
+}
@@ -0,0 +1,198 @@
+use super::threshold_utils::{ThresholdLevel, Thresholds};
+use super::{color_utils, Segment, SegmentData};
+use crate::config::{InputData, SegmentId};
+use crate::core::spend::format_tokens;
+use crate::core::transcript::{self, Entry};
+use crate::utils::TimeFormatter;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// One compaction of the session
+#[derive(Debug, Clone, Default)]
+struct Compaction {
+    time: Option<DateTime<Utc>>,
+    /// Context tokens just before compacting
+    tokens: Option<u64>,
+    /// "auto" or "manual", when recorded
+    trigger: Option<String>,
+}
+
+/// Compactions found in a transcript
+#[derive(Debug, Default)]
+struct CompactionHistory {
+    compactions: Vec<Compaction>,
+}
+
+impl CompactionHistory {
+    /// Only compaction markers count; summary entries are also written for other reasons,
+    /// such as resumed sessions, so they can't tell a compaction apart
+    fn load(path: &Path) -> Option<Self> {
+        let mut compactions: Vec<Compaction> = Vec::new();
+        let mut last_main_tokens: Option<u64> = None;
+
+        for entry in transcript::entries(path).ok()? {
+            if entry.is_compact_boundary() {
+                let metadata = entry.compact_metadata.clone().unwrap_or_default();
+                compactions.push(Compaction {
+                    time: entry.time(),
+                    tokens: metadata.pre_tokens.or(last_main_tokens),
+                    trigger: metadata.trigger,
+                });
+                continue;
+            }
+
+            if let Some(tokens) = main_chain_tokens(&entry) {
+                last_main_tokens = Some(tokens);
+            }
+        }
+
+        Some(Self { compactions })
+    }
+
+    /// Time between the last two compactions
+    /// A single compaction has no interval: a session may be resumed or start with a
+    /// large context, so its first compaction says nothing about how fast context refills
+    fn last_interval(&self) -> Option<chrono::Duration> {
+        let mut times = self.compactions.iter().rev().map(|c| c.time);
+        let last = times.next()??;
+        let previous = times.next()??;
+        Some(last - previous)
+    }
+}
+
+/// Context tokens of a main-chain assistant message
+fn main_chain_tokens(entry: &Entry) -> Option<u64> {
+    if entry.is_sidechain || !entry.is_assistant() {
+        return None;
+    }
+    Some(entry.usage()?.display_tokens() as u64)
+}
+
+pub struct CompactionSegment {
+    warning_minutes: u64,
+    critical_minutes: u64,
+    thresholds: Thresholds,
+}
+
+impl Default for CompactionSegment {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CompactionSegment {
+    pub fn new() -> Self {
+        Self {
+            warning_minutes: 60,
+            critical_minutes: 20,
+            thresholds: Thresholds::default(),
+        }
+    }
+
+    /// Warning and critical settings from the segment's options
+    pub fn with_thresholds(mut self, thresholds: Thresholds) -> Self {
+        self.thresholds = thresholds;
+        self
+    }
+
+    /// Warn when a compaction follows the previous one within these many minutes
+    pub fn with_intervals(mut self, warning_minutes: u64, critical_minutes: u64) -> Self {
+        self.warning_minutes = warning_minutes;
+        self.critical_minutes = critical_minutes;
+        self
+    }
+
+    fn level_for_interval(&self, interval: chrono::Duration) -> Option<ThresholdLevel> {
+        let minutes = interval.num_minutes().max(0) as u64;
+        if minutes < self.critical_minutes {
+            Some(ThresholdLevel::Critical)
+        } else if minutes < self.warning_minutes {
+            Some(ThresholdLevel::Warning)
+        } else {
+            None
+        }
+    }
+}
+
+impl Segment for CompactionSegment {
+    fn collect(&self, input: &InputData) -> Option<SegmentData> {
+        let history = CompactionHistory::load(Path::new(&input.transcript_path))?;
+        let count = history.compactions.len();
+
+        let mut metadata = HashMap::new();
+        metadata.insert("compaction_count".to_string(), count.to_string());
+
+        let last = match history.compactions.last() {
+            Some(last) => last,
+            None => {
+                return Some(SegmentData {
+                    primary: "0×".to_string(),
+                    secondary: String::new(),
+                    metadata,
+                })
+            }
+        };
+
+        for trigger in ["auto", "manual"] {
+            let triggered = history
+                .compactions
+                .iter()
+                .filter(|c| c.trigger.as_deref() == Some(trigger))
+                .count();
+            metadata.insert(format!("{}_count", trigger), triggered.to_string());
+        }
+        if let Some(trigger) = &last.trigger {
+            metadata.insert("last_trigger".to_string(), trigger.clone());
+        }
+
+        let mut details = Vec::new();
+        if let Some(tokens) = last.tokens {
+            details.push(format!("at {}", format_tokens(tokens)));
+            metadata.insert("last_compaction_tokens".to_string(), tokens.to_string());
+        }
+        if let Some(time) = last.time {
+            let since = Utc::now() - time;
+            details.push(format!("{} ago", TimeFormatter::format_countdown(since)));
+            metadata.insert("last_compaction_at".to_string(), time.to_rfc3339());
+            metadata.insert(
+                "minutes_since_last".to_string(),
+                since.num_minutes().max(0).to_string(),
+            );
+        }
+
+        let secondary = if details.is_empty() {
+            String::new()
+        } else {
+            format!("· {}", details.join(" · "))
+        };
+
+        if let Some(interval) = history.last_interval() {
+            metadata.insert(
+                "last_interval_minutes".to_string(),
+                interval.num_minutes().max(0).to_string(),
+            );
+
+            // Compacting again soon after the previous one means the context refills quickly
+            if let Some(level) = self.level_for_interval(interval) {
+                if let Some(color) = self.thresholds.color_for_level(level) {
+                    let color_json = color_utils::serialize_ansi_color_to_json(&color);
+                    metadata.insert("text_color_override".to_string(), color_json);
+                }
+                if let Some(should_bold) = self.thresholds.bold_for_level(level) {
+                    metadata.insert("text_bold_override".to_string(), should_bold.to_string());
+                }
+            }
+        }
+
+        Some(SegmentData {
+            primary: format!("{}×", count),
+            secondary,
+            metadata,
+        })
+    }
+
+    fn id(&self) -> SegmentId {
+        SegmentId::Compaction
+    }
+}
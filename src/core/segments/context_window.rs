@@ -1,35 +1,119 @@
-use super::{color_utils, threshold_utils, Segment, SegmentData};
-use crate::config::{InputData, ModelConfig, SegmentId, TranscriptEntry};
+use super::threshold_utils::Thresholds;
+use super::{color_utils, Segment, SegmentData};
+use crate::config::{InputData, ModelConfig, SegmentId};
+use crate::core::transcript::{self, ContextUsage};
 use std::collections::HashMap;
-use std::fs;
-use std::io::{BufRead, BufReader};
-use std::path::{Path, PathBuf};
+use std::path::Path;
+
+/// Context size Claude Code's `exceeds_200k_tokens` flag refers to
+const EXCEEDS_TOKENS: u32 = 200_000;
+
+/// What the context percentage is relative to
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ContextBase {
+    /// The model's full context limit
+    #[default]
+    Limit,
+    /// The usable window before Claude Code autocompacts (limit minus autocompact buffer)
+    Usable,
+}
 
-#[derive(Default)]
-pub struct ContextWindowSegment;
+impl ContextBase {
+    pub fn from_option(value: Option<&str>) -> Self {
+        match value {
+            Some("usable") => Self::Usable,
+            _ => Self::Limit,
+        }
+    }
+}
+
+pub struct ContextWindowSegment {
+    include_output: bool,
+    base: ContextBase,
+    show_forecast: bool,
+    thresholds: Thresholds,
+}
+
+impl Default for ContextWindowSegment {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
 impl ContextWindowSegment {
     pub fn new() -> Self {
-        Self
+        Self {
+            include_output: true,
+            base: ContextBase::Limit,
+            show_forecast: false,
+            thresholds: Thresholds::default(),
+        }
     }
 
-    /// Get context limit for the specified model
-    fn get_context_limit_for_model(model_id: &str) -> u32 {
-        let model_config = ModelConfig::load();
-        model_config.get_context_limit(model_id)
+    /// Warning and critical settings from the segment's options
+    pub fn with_thresholds(mut self, thresholds: Thresholds) -> Self {
+        self.thresholds = thresholds;
+        self
+    }
+
+    /// Count the last turn's output tokens, which become input on the next turn
+    pub fn with_output(mut self, include_output: bool) -> Self {
+        self.include_output = include_output;
+        self
+    }
+
+    pub fn with_base(mut self, base: ContextBase) -> Self {
+        self.base = base;
+        self
+    }
+
+    /// Show the estimated turns left before autocompact
+    pub fn with_forecast(mut self, show_forecast: bool) -> Self {
+        self.show_forecast = show_forecast;
+        self
     }
 }
 
 impl Segment for ContextWindowSegment {
     fn collect(&self, input: &InputData) -> Option<SegmentData> {
-        // Dynamically determine context limit based on current model ID
-        let context_limit = Self::get_context_limit_for_model(&input.model.id);
+        // Dynamically determine context limit and autocompact buffer based on current model ID
+        let model_config = ModelConfig::load();
+        let context_limit = model_config.get_context_limit(&input.model.id);
+        let autocompact_buffer = model_config
+            .get_autocompact_buffer(&input.model.id)
+            .min(context_limit);
+        let usable_limit = (context_limit - autocompact_buffer).max(1);
+        let percentage_base = match self.base {
+            ContextBase::Limit => context_limit,
+            ContextBase::Usable => usable_limit,
+        };
 
-        let context_used_token_opt = parse_transcript_usage(&input.transcript_path);
+        let context_usage = parse_transcript_usage(&input.transcript_path);
+        let context_used_token_opt = context_usage
+            .as_ref()
+            .and_then(|usage| usage.main.as_ref())
+            .map(|usage| {
+                if self.include_output {
+                    usage.display_tokens()
+                } else {
+                    usage.context_tokens_without_output()
+                }
+            });
+
+        // Claude Code flags contexts past 200k tokens; when the transcript shows less
+        // (not written yet, or unreadable), 200k is the best known lower bound
+        let exceeds_200k = input.exceeds_200k_tokens.unwrap_or(false);
+        let lower_bound = exceeds_200k
+            && context_used_token_opt.is_none_or(|tokens| tokens <= EXCEEDS_TOKENS);
+        let context_used_token_opt = if lower_bound {
+            Some(EXCEEDS_TOKENS)
+        } else {
+            context_used_token_opt
+        };
 
         let (percentage_display, tokens_display) = match context_used_token_opt {
             Some(context_used_token) => {
-                let context_used_rate = (context_used_token as f64 / context_limit as f64) * 100.0;
+                let context_used_rate = (context_used_token as f64 / percentage_base as f64) * 100.0;
 
                 let percentage = if context_used_rate.fract() == 0.0 {
                     format!("{:.0}%", context_used_rate)
@@ -48,7 +132,11 @@ impl Segment for ContextWindowSegment {
                     context_used_token.to_string()
                 };
 
-                (percentage, tokens)
+                if lower_bound {
+                    (format!("≥{}", percentage), format!("{}+", tokens))
+                } else {
+                    (percentage, tokens)
+                }
             }
             None => {
                 // No usage data available
@@ -59,19 +147,19 @@ impl Segment for ContextWindowSegment {
         let mut metadata = HashMap::new();
         match context_used_token_opt {
             Some(context_used_token) => {
-                let context_used_rate = (context_used_token as f64 / context_limit as f64) * 100.0;
+                let context_used_rate = (context_used_token as f64 / percentage_base as f64) * 100.0;
                 metadata.insert("tokens".to_string(), context_used_token.to_string());
                 metadata.insert("percentage".to_string(), context_used_rate.to_string());
 
                 // Check if we need to apply threshold-based color override
-                if let Some(color) = threshold_utils::get_color_for_utilization(SegmentId::ContextWindow, context_used_rate) {
+                if let Some(color) = self.thresholds.color_for_utilization(context_used_rate) {
                     // Serialize the color to JSON for metadata using shared helper
                     let color_json = color_utils::serialize_ansi_color_to_json(&color);
                     metadata.insert("text_color_override".to_string(), color_json);
                 }
 
                 // Check if we need to apply threshold-based bold override
-                if let Some(should_bold) = threshold_utils::should_be_bold(SegmentId::ContextWindow, context_used_rate) {
+                if let Some(should_bold) = self.thresholds.bold_for_utilization(context_used_rate) {
                     metadata.insert("text_bold_override".to_string(), should_bold.to_string());
                 }
             }
@@ -81,11 +169,59 @@ impl Segment for ContextWindowSegment {
             }
         }
         metadata.insert("limit".to_string(), context_limit.to_string());
+        metadata.insert("usable_limit".to_string(), usable_limit.to_string());
+        metadata.insert(
+            "autocompact_buffer".to_string(),
+            autocompact_buffer.to_string(),
+        );
         metadata.insert("model".to_string(), input.model.id.clone());
+        if let Some(exceeds) = input.exceeds_200k_tokens {
+            metadata.insert("exceeds_200k_tokens".to_string(), exceeds.to_string());
+        }
+        if lower_bound {
+            metadata.insert("tokens_lower_bound".to_string(), "true".to_string());
+        }
+
+        // Room left before autocompact, and how many average turns fit in it
+        let mut secondary = String::new();
+        if let Some(context_used_token) = context_used_token_opt {
+            let tokens_left = usable_limit.saturating_sub(context_used_token);
+            let percent_left = tokens_left as f64 / usable_limit as f64 * 100.0;
+            metadata.insert(
+                "autocompact_tokens_left".to_string(),
+                tokens_left.to_string(),
+            );
+            metadata.insert("autocompact_percent".to_string(), percent_left.to_string());
+
+            if let Some(average) = context_usage
+                .as_ref()
+                .and_then(|usage| usage.average_turn_tokens)
+                .filter(|average| *average > 0.0)
+            {
+                let turns_remaining = (tokens_left as f64 / average).floor() as u64;
+                metadata.insert("average_turn_tokens".to_string(), average.round().to_string());
+                metadata.insert("turns_remaining".to_string(), turns_remaining.to_string());
+                if self.show_forecast {
+                    secondary = format!("~{} turns left", turns_remaining);
+                }
+            }
+        }
+
+        // Subagents run in their own context, reported separately from the main thread
+        if let Some(subagent) = context_usage.and_then(|usage| usage.subagent) {
+            metadata.insert(
+                "subagent_tokens".to_string(),
+                subagent.display_tokens().to_string(),
+            );
+            metadata.insert(
+                "subagent_output_tokens".to_string(),
+                subagent.output_tokens.to_string(),
+            );
+        }
 
         Some(SegmentData {
             primary: format!("{} · {} tokens", percentage_display, tokens_display),
-            secondary: String::new(),
+            secondary,
             metadata,
         })
     }
@@ -95,189 +231,17 @@ impl Segment for ContextWindowSegment {
     }
 }
 
-fn parse_transcript_usage<P: AsRef<Path>>(transcript_path: P) -> Option<u32> {
+fn parse_transcript_usage<P: AsRef<Path>>(transcript_path: P) -> Option<ContextUsage> {
     let path = transcript_path.as_ref();
 
     // Try to parse from current transcript file
-    if let Some(usage) = try_parse_transcript_file(path) {
+    if let Some(usage) = transcript::context_usage(path).filter(|usage| usage.main.is_some()) {
         return Some(usage);
     }
 
     // If file doesn't exist, try to find usage from project history
     if !path.exists() {
-        if let Some(usage) = try_find_usage_from_project_history(path) {
-            return Some(usage);
-        }
-    }
-
-    None
-}
-
-fn try_parse_transcript_file(path: &Path) -> Option<u32> {
-    let file = fs::File::open(path).ok()?;
-    let reader = BufReader::new(file);
-    let lines: Vec<String> = reader
-        .lines()
-        .collect::<Result<Vec<_>, _>>()
-        .unwrap_or_default();
-
-    if lines.is_empty() {
-        return None;
-    }
-
-    // Check if the last line is a summary
-    let last_line = lines.last()?.trim();
-    if let Ok(entry) = serde_json::from_str::<TranscriptEntry>(last_line) {
-        if entry.r#type.as_deref() == Some("summary") {
-            // Handle summary case: find usage by leafUuid
-            if let Some(leaf_uuid) = &entry.leaf_uuid {
-                let project_dir = path.parent()?;
-                return find_usage_by_leaf_uuid(leaf_uuid, project_dir);
-            }
-        }
-    }
-
-    // Normal case: find the last assistant message in current file
-    for line in lines.iter().rev() {
-        let line = line.trim();
-        if line.is_empty() {
-            continue;
-        }
-
-        if let Ok(entry) = serde_json::from_str::<TranscriptEntry>(line) {
-            if entry.r#type.as_deref() == Some("assistant") {
-                if let Some(message) = &entry.message {
-                    if let Some(raw_usage) = &message.usage {
-                        let normalized = raw_usage.clone().normalize();
-                        return Some(normalized.display_tokens());
-                    }
-                }
-            }
-        }
-    }
-
-    None
-}
-
-fn find_usage_by_leaf_uuid(leaf_uuid: &str, project_dir: &Path) -> Option<u32> {
-    // Search for the leafUuid across all session files in the project directory
-    let entries = fs::read_dir(project_dir).ok()?;
-
-    for entry in entries {
-        let entry = entry.ok()?;
-        let path = entry.path();
-
-        if path.extension().and_then(|s| s.to_str()) != Some("jsonl") {
-            continue;
-        }
-
-        if let Some(usage) = search_uuid_in_file(&path, leaf_uuid) {
-            return Some(usage);
-        }
-    }
-
-    None
-}
-
-fn search_uuid_in_file(path: &Path, target_uuid: &str) -> Option<u32> {
-    let file = fs::File::open(path).ok()?;
-    let reader = BufReader::new(file);
-    let lines: Vec<String> = reader
-        .lines()
-        .collect::<Result<Vec<_>, _>>()
-        .unwrap_or_default();
-
-    // Find the message with target_uuid
-    for line in &lines {
-        let line = line.trim();
-        if line.is_empty() {
-            continue;
-        }
-
-        if let Ok(entry) = serde_json::from_str::<TranscriptEntry>(line) {
-            if let Some(uuid) = &entry.uuid {
-                if uuid == target_uuid {
-                    // Found the target message, check its type
-                    if entry.r#type.as_deref() == Some("assistant") {
-                        // Direct assistant message with usage
-                        if let Some(message) = &entry.message {
-                            if let Some(raw_usage) = &message.usage {
-                                let normalized = raw_usage.clone().normalize();
-                                return Some(normalized.display_tokens());
-                            }
-                        }
-                    } else if entry.r#type.as_deref() == Some("user") {
-                        // User message, need to find the parent assistant message
-                        if let Some(parent_uuid) = &entry.parent_uuid {
-                            return find_assistant_message_by_uuid(&lines, parent_uuid);
-                        }
-                    }
-                    break;
-                }
-            }
-        }
-    }
-
-    None
-}
-
-fn find_assistant_message_by_uuid(lines: &[String], target_uuid: &str) -> Option<u32> {
-    for line in lines {
-        let line = line.trim();
-        if line.is_empty() {
-            continue;
-        }
-
-        if let Ok(entry) = serde_json::from_str::<TranscriptEntry>(line) {
-            if let Some(uuid) = &entry.uuid {
-                if uuid == target_uuid && entry.r#type.as_deref() == Some("assistant") {
-                    if let Some(message) = &entry.message {
-                        if let Some(raw_usage) = &message.usage {
-                            let normalized = raw_usage.clone().normalize();
-                            return Some(normalized.display_tokens());
-                        }
-                    }
-                }
-            }
-        }
-    }
-
-    None
-}
-
-fn try_find_usage_from_project_history(transcript_path: &Path) -> Option<u32> {
-    let project_dir = transcript_path.parent()?;
-
-    // Find the most recent session file in the project directory
-    let mut session_files: Vec<PathBuf> = Vec::new();
-    let entries = fs::read_dir(project_dir).ok()?;
-
-    for entry in entries {
-        let entry = entry.ok()?;
-        let path = entry.path();
-
-        if path.extension().and_then(|s| s.to_str()) == Some("jsonl") {
-            session_files.push(path);
-        }
-    }
-
-    if session_files.is_empty() {
-        return None;
-    }
-
-    // Sort by modification time (most recent first)
-    session_files.sort_by_key(|path| {
-        fs::metadata(path)
-            .and_then(|m| m.modified())
-            .unwrap_or(std::time::UNIX_EPOCH)
-    });
-    session_files.reverse();
-
-    // Try to find usage from the most recent session
-    for session_path in &session_files {
-        if let Some(usage) = try_parse_transcript_file(session_path) {
-            return Some(usage);
-        }
+        return transcript::project_context_usage(path.parent()?);
     }
 
     None
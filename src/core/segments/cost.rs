@@ -1,37 +1,195 @@
-use super::{Segment, SegmentData};
-use crate::config::{InputData, SegmentId};
+use super::threshold_utils::{self, Thresholds};
+use super::{color_utils, Segment, SegmentData};
+use crate::config::{InputData, ModelConfig, SegmentId};
+use crate::core::transcript::{self, MessageDeduper};
 use std::collections::HashMap;
+use std::path::Path;
+
+/// Where the cost segment takes its value from
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum CostSource {
+    /// Claude Code's cost.total_cost_usd, falling back to the transcript when missing
+    #[default]
+    Auto,
+    /// Only Claude Code's cost.total_cost_usd
+    ClaudeCode,
+    /// Always compute from transcript usage and models.toml pricing
+    Transcript,
+}
+
+impl CostSource {
+    pub fn from_option(value: Option<&str>) -> Self {
+        match value {
+            Some("claude_code") => Self::ClaudeCode,
+            Some("transcript") => Self::Transcript,
+            _ => Self::Auto,
+        }
+    }
+}
+
+/// Session spend summed from transcript usage records
+#[derive(Debug, Default)]
+pub struct TranscriptCost {
+    pub total_usd: f64,
+    pub priced_messages: u32,
+    /// Messages whose model has no pricing in models.toml
+    pub unpriced_messages: u32,
+}
 
 #[derive(Default)]
-pub struct CostSegment;
+pub struct CostSegment {
+    source: CostSource,
+    budget_usd: Option<f64>,
+    show_remaining: bool,
+    thresholds: Thresholds,
+}
 
 impl CostSegment {
     pub fn new() -> Self {
-        Self
+        Self::default()
+    }
+
+    /// Warning and critical settings from the segment's options
+    pub fn with_thresholds(mut self, thresholds: Thresholds) -> Self {
+        self.thresholds = thresholds;
+        self
+    }
+
+    pub fn with_source(mut self, source: CostSource) -> Self {
+        self.source = source;
+        self
+    }
+
+    /// Session budget in USD; thresholds then apply to the percent of budget spent
+    pub fn with_budget(mut self, budget_usd: Option<f64>) -> Self {
+        self.budget_usd = budget_usd.filter(|budget| *budget > 0.0);
+        self
+    }
+
+    pub fn with_remaining(mut self, show_remaining: bool) -> Self {
+        self.show_remaining = show_remaining;
+        self
+    }
+
+    pub fn format_cost(cost: f64) -> String {
+        if cost == 0.0 || cost < 0.01 {
+            "$0".to_string()
+        } else {
+            format!("${:.2}", cost)
+        }
+    }
+
+    /// Sum the cost of every assistant message in a transcript
+    /// Messages are priced by their own model, falling back to `default_model`
+    pub fn calculate_transcript_cost(
+        path: &Path,
+        default_model: &str,
+        model_config: &ModelConfig,
+    ) -> Option<TranscriptCost> {
+        let mut deduper = MessageDeduper::new();
+        let mut cost = TranscriptCost::default();
+
+        for entry in transcript::entries(path).ok()? {
+            if !entry.is_assistant() || !deduper.first_seen(&entry) {
+                continue;
+            }
+
+            let usage = match entry.usage() {
+                Some(usage) => usage,
+                None => continue,
+            };
+
+            let model_id = entry.model().unwrap_or(default_model);
+            match model_config.get_pricing(model_id) {
+                Some(pricing) => {
+                    cost.total_usd += pricing.cost(&usage);
+                    cost.priced_messages += 1;
+                }
+                None => cost.unpriced_messages += 1,
+            }
+        }
+
+        if cost.priced_messages == 0 {
+            return None;
+        }
+
+        Some(cost)
+    }
+
+    fn transcript_cost(&self, input: &InputData) -> Option<TranscriptCost> {
+        let model_config = ModelConfig::load();
+        Self::calculate_transcript_cost(
+            Path::new(&input.transcript_path),
+            &input.model.id,
+            &model_config,
+        )
     }
 }
 
 impl Segment for CostSegment {
     fn collect(&self, input: &InputData) -> Option<SegmentData> {
-        let cost_data = input.cost.as_ref()?;
-
-        // Primary display: total cost
-        let primary = if let Some(cost) = cost_data.total_cost_usd {
-            if cost == 0.0 || cost < 0.01 {
-                "$0".to_string()
-            } else {
-                format!("${:.2}", cost)
+        let reported_cost = input
+            .cost
+            .as_ref()
+            .and_then(|cost_data| cost_data.total_cost_usd);
+
+        let mut metadata = HashMap::new();
+
+        let (cost, estimated) = match self.source {
+            CostSource::ClaudeCode => (reported_cost?, false),
+            CostSource::Auto if reported_cost.is_some_and(|cost| cost > 0.0) => {
+                (reported_cost?, false)
             }
+            CostSource::Auto | CostSource::Transcript => match self.transcript_cost(input) {
+                Some(transcript_cost) => {
+                    metadata.insert(
+                        "unpriced_messages".to_string(),
+                        transcript_cost.unpriced_messages.to_string(),
+                    );
+                    (transcript_cost.total_usd, true)
+                }
+                // Keep Claude Code's (zero) cost when the transcript can't be priced
+                None if self.source == CostSource::Auto => (reported_cost?, false),
+                None => return None,
+            },
+        };
+
+        // Primary display: total cost, marked with "~" when estimated locally
+        let primary = if estimated {
+            format!("~{}", Self::format_cost(cost))
         } else {
-            return None;
+            Self::format_cost(cost)
+        };
+
+        // Secondary display: remaining budget when enabled, otherwise empty
+        let secondary = match self.budget_usd {
+            Some(budget) if self.show_remaining => {
+                threshold_utils::format_budget_remaining(cost, budget)
+            }
+            _ => String::new(),
         };
 
-        // Secondary display: empty for cost segment
-        let secondary = String::new();
+        metadata.insert("cost".to_string(), cost.to_string());
+        metadata.insert(
+            "cost_source".to_string(),
+            if estimated { "transcript" } else { "claude_code" }.to_string(),
+        );
 
-        let mut metadata = HashMap::new();
-        if let Some(cost) = cost_data.total_cost_usd {
-            metadata.insert("cost".to_string(), cost.to_string());
+        if let Some(utilization) = threshold_utils::budget_utilization(cost, self.budget_usd) {
+            metadata.insert("budget_usd".to_string(), self.budget_usd.unwrap_or_default().to_string());
+            metadata.insert("budget_utilization".to_string(), utilization.to_string());
+
+            // Check if we need to apply threshold-based color override
+            if let Some(color) = self.thresholds.color_for_utilization(utilization) {
+                // Serialize the color to JSON for metadata using shared helper
+                let color_json = color_utils::serialize_ansi_color_to_json(&color);
+                metadata.insert("text_color_override".to_string(), color_json);
+            }
+
+            // Check if we need to apply threshold-based bold override
+            if let Some(should_bold) = self.thresholds.bold_for_utilization(utilization) {
+                metadata.insert("text_bold_override".to_string(), should_bold.to_string());
+            }
         }
 
         Some(SegmentData {
@@ -1,13 +1,62 @@
-use super::{Segment, SegmentData};
+use super::threshold_utils::{ThresholdLevel, Thresholds};
+use super::{color_utils, Segment, SegmentData};
 use crate::config::{InputData, SegmentId};
 use std::collections::HashMap;
 
-#[derive(Default)]
-pub struct DirectorySegment;
+/// Separator between the project name and the path below it
+const PROJECT_SEPARATOR: &str = " › ";
+
+pub struct DirectorySegment {
+    show_project: bool,
+    warn_outside_project: bool,
+    thresholds: Thresholds,
+}
+
+impl Default for DirectorySegment {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
 impl DirectorySegment {
     pub fn new() -> Self {
-        Self
+        Self {
+            show_project: true,
+            warn_outside_project: true,
+            thresholds: Thresholds::default(),
+        }
+    }
+
+    /// Warning and critical settings from the segment's options
+    pub fn with_thresholds(mut self, thresholds: Thresholds) -> Self {
+        self.thresholds = thresholds;
+        self
+    }
+
+    /// Show "project › subdir" when the current directory is below the project root
+    pub fn with_project(mut self, show_project: bool) -> Self {
+        self.show_project = show_project;
+        self
+    }
+
+    /// Use the warning color when the current directory is outside the project root
+    pub fn with_outside_warning(mut self, warn_outside_project: bool) -> Self {
+        self.warn_outside_project = warn_outside_project;
+        self
+    }
+
+    /// Path of `path` below `root` with '/' separators, "" for the root itself,
+    /// or None when `path` is outside `root`
+    fn relative_path(path: &str, root: &str) -> Option<String> {
+        let normalize = |p: &str| p.replace('\\', "/").trim_end_matches('/').to_string();
+        let (path, root) = (normalize(path), normalize(root));
+
+        if path == root {
+            Some(String::new())
+        } else {
+            path.strip_prefix(&format!("{}/", root))
+                .map(|rest| rest.to_string())
+        }
     }
 
     /// Extract directory name from path, handling both Unix and Windows separators
@@ -39,6 +88,9 @@ impl DirectorySegment {
 impl Segment for DirectorySegment {
     fn collect(&self, input: &InputData) -> Option<SegmentData> {
         let current_dir = &input.workspace.current_dir;
+        if current_dir.is_empty() {
+            return None;
+        }
 
         // Handle cross-platform path separators manually for better compatibility
         let dir_name = Self::extract_directory_name(current_dir);
@@ -47,9 +99,57 @@ impl Segment for DirectorySegment {
         let mut metadata = HashMap::new();
         metadata.insert("full_path".to_string(), current_dir.clone());
 
+        let mut primary = dir_name;
+        let mut secondary = String::new();
+
+        // Older inputs have no project root; show the directory name only
+        let project_dir = input
+            .workspace
+            .project_dir
+            .as_deref()
+            .filter(|dir| !dir.is_empty());
+        if let Some(project_dir) = project_dir {
+            let project_name =
+                Self::extract_directory_name(project_dir.trim_end_matches(['/', '\\']));
+            metadata.insert("project_dir".to_string(), project_dir.to_string());
+
+            match Self::relative_path(current_dir, project_dir) {
+                Some(relative) => {
+                    metadata.insert("outside_project".to_string(), "false".to_string());
+                    if !relative.is_empty() {
+                        metadata.insert("relative_path".to_string(), relative.clone());
+                        if self.show_project {
+                            primary = format!("{}{}{}", project_name, PROJECT_SEPARATOR, relative);
+                        }
+                    }
+                }
+                None => {
+                    metadata.insert("outside_project".to_string(), "true".to_string());
+                    if self.show_project {
+                        secondary = format!("· outside {}", project_name);
+                    }
+
+                    if self.warn_outside_project {
+                        if let Some(color) =
+                            self.thresholds.color_for_level(ThresholdLevel::Warning)
+                        {
+                            let color_json = color_utils::serialize_ansi_color_to_json(&color);
+                            metadata.insert("text_color_override".to_string(), color_json);
+                        }
+                        if let Some(should_bold) =
+                            self.thresholds.bold_for_level(ThresholdLevel::Warning)
+                        {
+                            metadata
+                                .insert("text_bold_override".to_string(), should_bold.to_string());
+                        }
+                    }
+                }
+            }
+        }
+
         Some(SegmentData {
-            primary: dir_name,
-            secondary: String::new(),
+            primary,
+            secondary,
             metadata,
         })
     }
@@ -0,0 +1,108 @@
+use super::{Segment, SegmentData};
+use crate::config::{InputData, SegmentId};
+use crate::core::transcript;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+/// Tools whose calls modify the file named in their input
+const EDIT_TOOLS: [&str; 4] = ["Edit", "MultiEdit", "Write", "NotebookEdit"];
+
+/// Files modified in a session, least recently touched first
+fn touched_files(path: &Path) -> Option<Vec<String>> {
+    // (tool_use id, file path) in call order
+    let mut edits: Vec<(String, String)> = Vec::new();
+    let mut failed: HashSet<String> = HashSet::new();
+
+    for entry in transcript::entries(path).ok()? {
+        for tool_use in entry.tool_uses() {
+            if !EDIT_TOOLS.contains(&tool_use.name.as_str()) {
+                continue;
+            }
+            let field = |key: &str| tool_use.input.get(key).and_then(|v| v.as_str());
+            if let Some(file) = field("file_path").or_else(|| field("notebook_path")) {
+                edits.push((tool_use.id.clone(), file.to_string()));
+            }
+        }
+        for tool_result in entry.tool_results() {
+            if tool_result.is_error {
+                failed.insert(tool_result.tool_use_id.clone());
+            }
+        }
+    }
+
+    // Failed edits left the file unchanged; a file touched again moves to the end
+    let mut files: Vec<String> = Vec::new();
+    for (id, file) in edits {
+        if failed.contains(&id) {
+            continue;
+        }
+        files.retain(|existing| *existing != file);
+        files.push(file);
+    }
+    Some(files)
+}
+
+pub struct FilesTouchedSegment {
+    show_last: bool,
+}
+
+impl Default for FilesTouchedSegment {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FilesTouchedSegment {
+    pub fn new() -> Self {
+        Self { show_last: true }
+    }
+
+    /// Show the name of the most recently modified file
+    pub fn with_last(mut self, show_last: bool) -> Self {
+        self.show_last = show_last;
+        self
+    }
+}
+
+impl Segment for FilesTouchedSegment {
+    fn collect(&self, input: &InputData) -> Option<SegmentData> {
+        let files = touched_files(Path::new(&input.transcript_path))?;
+        let last = files.last()?;
+
+        let primary = if files.len() == 1 {
+            "1 file".to_string()
+        } else {
+            format!("{} files", files.len())
+        };
+
+        let last_name = Path::new(last)
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_else(|| last.clone());
+        let secondary = if self.show_last {
+            format!("· {}", last_name)
+        } else {
+            String::new()
+        };
+
+        let mut metadata = HashMap::new();
+        metadata.insert("count".to_string(), files.len().to_string());
+        metadata.insert("last_file".to_string(), last.clone());
+        // Most recent first, as a JSON array
+        let recent_first: Vec<&String> = files.iter().rev().collect();
+        metadata.insert(
+            "files".to_string(),
+            serde_json::to_string(&recent_first).unwrap_or_default(),
+        );
+
+        Some(SegmentData {
+            primary,
+            secondary,
+            metadata,
+        })
+    }
+
+    fn id(&self) -> SegmentId {
+        SegmentId::FilesTouched
+    }
+}
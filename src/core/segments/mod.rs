@@ -1,12 +1,26 @@
+pub mod burn_rate;
+pub mod cache_hit;
+pub mod claude_version;
 pub mod color_utils;
+pub mod compaction;
 pub mod context_window;
 pub mod threshold_utils;
 pub mod cost;
 pub mod directory;
+pub mod files_touched;
 pub mod git;
 pub mod model;
 pub mod output_style;
+pub mod progress_bar;
 pub mod session;
+pub mod session_id;
+pub mod spend;
+pub mod subagents;
+pub mod thinking;
+pub mod time;
+pub mod todos;
+pub mod tool_activity;
+pub mod turn_stats;
 pub mod update;
 pub mod usage;
 pub mod usage_5hour;
@@ -29,13 +43,26 @@ pub struct SegmentData {
 }
 
 // Re-export all segment types
+pub use burn_rate::BurnRateSegment;
+pub use cache_hit::CacheHitSegment;
+pub use claude_version::ClaudeVersionSegment;
+pub use compaction::CompactionSegment;
 pub use context_window::ContextWindowSegment;
 pub use cost::CostSegment;
 pub use directory::DirectorySegment;
+pub use files_touched::FilesTouchedSegment;
 pub use git::GitSegment;
 pub use model::ModelSegment;
 pub use output_style::OutputStyleSegment;
 pub use session::SessionSegment;
+pub use session_id::SessionIdSegment;
+pub use spend::SpendSegment;
+pub use subagents::SubagentsSegment;
+pub use thinking::ThinkingSegment;
+pub use time::TimeSegment;
+pub use todos::TodosSegment;
+pub use tool_activity::ToolActivitySegment;
+pub use turn_stats::TurnStatsSegment;
 pub use update::UpdateSegment;
 pub use usage::UsageSegment;
 pub use usage_5hour::Usage5HourSegment;
@@ -1,25 +1,262 @@
-use super::{Segment, SegmentData};
+use super::threshold_utils::{ThresholdLevel, Thresholds};
+use super::{color_utils, Segment, SegmentData};
 use crate::config::{InputData, ModelConfig, SegmentId};
+use crate::core::transcript::{self, MessageDeduper};
 use std::collections::HashMap;
+use std::path::Path;
 
-#[derive(Default)]
-pub struct ModelSegment;
+/// Model Claude Code records on error messages it generates itself
+const SYNTHETIC_MODEL: &str = "<synthetic>";
+
+/// Models that answered in a session, from assistant messages in the transcript
+#[derive(Debug, Default)]
+struct ServedModels {
+    /// Model of the newest main-chain reply
+    last: Option<String>,
+    /// Replies per model, in order of first use
+    turns: Vec<(String, u64)>,
+}
+
+impl ServedModels {
+    /// Find the model of the newest reply, reading from the end of the transcript.
+    /// `count_turns` scans the whole transcript to also count replies per model.
+    fn load(path: &Path, count_turns: bool) -> Option<Self> {
+        if !count_turns {
+            let last = transcript::entries_rev(path)
+                .ok()?
+                .filter(|entry| entry.is_assistant() && !entry.is_sidechain)
+                .find_map(|entry| {
+                    entry
+                        .model()
+                        .filter(|m| *m != SYNTHETIC_MODEL)
+                        .map(String::from)
+                });
+            return Some(Self {
+                last,
+                turns: Vec::new(),
+            });
+        }
+
+        let mut served = Self::default();
+        let mut deduper = MessageDeduper::new();
+
+        for entry in transcript::entries(path).ok()? {
+            if !entry.is_assistant() || !deduper.first_seen(&entry) {
+                continue;
+            }
+            let model = match entry.model() {
+                Some(model) if model != SYNTHETIC_MODEL => model,
+                _ => continue,
+            };
+
+            match served.turns.iter_mut().find(|(name, _)| name == model) {
+                Some((_, count)) => *count += 1,
+                None => served.turns.push((model.to_string(), 1)),
+            }
+            // Subagents may run on a different model by design
+            if !entry.is_sidechain {
+                served.last = Some(model.to_string());
+            }
+        }
+
+        Some(served)
+    }
+}
+
+/// Readable name for a Claude model id without a configured display name,
+/// e.g. "claude-opus-4-1-20250805" -> "Opus 4.1"; other ids are returned unchanged
+fn short_model_name(model_id: &str) -> String {
+    let parts: Vec<&str> = model_id.split(['-', '.', '@']).collect();
+    let family = parts
+        .iter()
+        .find(|part| matches!(**part, "opus" | "sonnet" | "haiku"));
+    let family = match family {
+        Some(family) => family,
+        None => return model_id.to_string(),
+    };
+
+    // Version numbers are short numeric parts; dates are 8 digits
+    let version: Vec<&str> = parts
+        .iter()
+        .filter(|part| part.len() <= 2 && part.chars().all(|c| c.is_ascii_digit()))
+        .copied()
+        .collect();
+
+    let mut name = family[..1].to_uppercase() + &family[1..];
+    if !version.is_empty() {
+        name.push(' ');
+        name.push_str(&version.join("."));
+    }
+    name
+}
+
+/// Model id without provider decoration, e.g. "us.anthropic.claude-sonnet-4-5-20250929-v1:0",
+/// "claude-sonnet-4-5@20250929" and "claude-sonnet-4-5[1m]" all become "claude-sonnet-4-5"
+fn base_model_id(id: &str) -> String {
+    let mut id = id.trim().to_lowercase();
+
+    // Context-window suffix ("[1m]") and Vertex version ("@20250929")
+    if let Some(end) = id.find(['[', '@']) {
+        id.truncate(end);
+    }
+    // Bedrock region and provider prefixes ("us.anthropic.")
+    if let Some(start) = id.rfind("anthropic.") {
+        id.drain(..start + "anthropic.".len());
+    }
+    // Bedrock model version ("-v1:0")
+    if let Some(start) = id.rfind("-v") {
+        let version = &id[start + 2..];
+        if version.contains(':') && version.chars().all(|c| c.is_ascii_digit() || c == ':') {
+            id.truncate(start);
+        }
+    }
+    // Release date ("-20250929")
+    if let Some((base, date)) = id.rsplit_once('-') {
+        if date.len() == 8 && date.chars().all(|c| c.is_ascii_digit()) {
+            id.truncate(base.len());
+        }
+    }
+    id
+}
+
+/// Whether two ids name the same model, ignoring provider prefixes and suffixes
+/// and a date on one side only ("claude-opus-4-1" vs "claude-opus-4-1-20250805")
+fn same_model(served: &str, requested: &str) -> bool {
+    base_model_id(served) == base_model_id(requested)
+}
+
+pub struct ModelSegment {
+    show_served: bool,
+    warn_on_mismatch: bool,
+    show_turn_counts: bool,
+    thresholds: Thresholds,
+}
+
+impl Default for ModelSegment {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
 impl ModelSegment {
     pub fn new() -> Self {
-        Self
+        Self {
+            show_served: true,
+            warn_on_mismatch: true,
+            show_turn_counts: false,
+            thresholds: Thresholds::default(),
+        }
+    }
+
+    /// Warning and critical settings from the segment's options
+    pub fn with_thresholds(mut self, thresholds: Thresholds) -> Self {
+        self.thresholds = thresholds;
+        self
+    }
+
+    /// Display the model that actually answered when it differs from the requested one
+    pub fn with_served(mut self, show_served: bool) -> Self {
+        self.show_served = show_served;
+        self
+    }
+
+    /// Use the warning color when the served model differs from the requested one
+    pub fn with_mismatch_warning(mut self, warn_on_mismatch: bool) -> Self {
+        self.warn_on_mismatch = warn_on_mismatch;
+        self
+    }
+
+    /// List how many replies each model served this session
+    pub fn with_turn_counts(mut self, show_turn_counts: bool) -> Self {
+        self.show_turn_counts = show_turn_counts;
+        self
     }
 }
 
 impl Segment for ModelSegment {
     fn collect(&self, input: &InputData) -> Option<SegmentData> {
+        if input.model.id.is_empty() && input.model.display_name.is_empty() {
+            return None;
+        }
+
+        let model_config = ModelConfig::load();
+        // Input without a display name still gets a readable one from the id
+        let display_name = if input.model.display_name.is_empty() {
+            short_model_name(&input.model.id)
+        } else {
+            input.model.display_name.clone()
+        };
+        let requested_name = self.format_model_name(&model_config, &input.model.id, &display_name);
+
         let mut metadata = HashMap::new();
         metadata.insert("model_id".to_string(), input.model.id.clone());
         metadata.insert("display_name".to_string(), input.model.display_name.clone());
 
+        let served = if self.show_served || self.show_turn_counts {
+            ServedModels::load(Path::new(&input.transcript_path), self.show_turn_counts)
+                .unwrap_or_default()
+        } else {
+            ServedModels::default()
+        };
+
+        let mut primary = requested_name.clone();
+        let mut details = Vec::new();
+
+        if let Some(served_id) = &served.last {
+            metadata.insert("served_model".to_string(), served_id.clone());
+            let mismatch = !same_model(served_id, &input.model.id);
+            metadata.insert("model_mismatch".to_string(), mismatch.to_string());
+
+            if mismatch && self.show_served {
+                primary =
+                    self.format_model_name(&model_config, served_id, &short_model_name(served_id));
+                details.push(format!("≠ {}", requested_name));
+
+                if self.warn_on_mismatch {
+                    if let Some(color) = self.thresholds.color_for_level(ThresholdLevel::Warning) {
+                        let color_json = color_utils::serialize_ansi_color_to_json(&color);
+                        metadata.insert("text_color_override".to_string(), color_json);
+                    }
+                    if let Some(should_bold) =
+                        self.thresholds.bold_for_level(ThresholdLevel::Warning)
+                    {
+                        metadata.insert("text_bold_override".to_string(), should_bold.to_string());
+                    }
+                }
+            }
+        }
+
+        if !served.turns.is_empty() {
+            let turns: serde_json::Map<String, serde_json::Value> = served
+                .turns
+                .iter()
+                .map(|(model, count)| (model.clone(), (*count).into()))
+                .collect();
+            metadata.insert(
+                "model_turns".to_string(),
+                serde_json::Value::Object(turns).to_string(),
+            );
+
+            // A single model serving every turn says nothing the name doesn't
+            if self.show_turn_counts && served.turns.len() > 1 {
+                let counts: Vec<String> = served
+                    .turns
+                    .iter()
+                    .map(|(model, count)| {
+                        format!(
+                            "{} ×{}",
+                            self.format_model_name(&model_config, model, &short_model_name(model)),
+                            count
+                        )
+                    })
+                    .collect();
+                details.push(counts.join(" "));
+            }
+        }
+
         Some(SegmentData {
-            primary: self.format_model_name(&input.model.id, &input.model.display_name),
-            secondary: String::new(),
+            primary,
+            secondary: details.join(" · "),
             metadata,
         })
     }
@@ -30,9 +267,12 @@ impl Segment for ModelSegment {
 }
 
 impl ModelSegment {
-    fn format_model_name(&self, id: &str, display_name: &str) -> String {
-        let model_config = ModelConfig::load();
-
+    fn format_model_name(
+        &self,
+        model_config: &ModelConfig,
+        id: &str,
+        display_name: &str,
+    ) -> String {
         // Try to get display name from external config first
         if let Some(config_name) = model_config.get_display_name(id) {
             config_name
@@ -0,0 +1,167 @@
+use super::SegmentData;
+use std::collections::HashMap;
+
+/// Partial cells for the eighth-block style, from 1/8 to 7/8 filled
+const EIGHTHS: [char; 7] = ['▏', '▎', '▍', '▌', '▋', '▊', '▉'];
+
+/// How bar cells are drawn
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum BarStyle {
+    /// Whole cells of the fill and empty characters, e.g. "▰▰▰▱▱"
+    #[default]
+    Blocks,
+    /// Unicode eighth blocks for sub-cell precision, e.g. "███▌  "
+    Eighths,
+}
+
+impl BarStyle {
+    /// Full cell drawn when `bar_fill` isn't set
+    fn default_fill(self) -> &'static str {
+        match self {
+            Self::Blocks => "▰",
+            Self::Eighths => "█",
+        }
+    }
+
+    /// Empty cell drawn when `bar_empty` isn't set; eighth blocks read best against blanks
+    fn default_empty(self) -> &'static str {
+        match self {
+            Self::Blocks => "▱",
+            Self::Eighths => " ",
+        }
+    }
+
+    pub fn from_option(value: Option<&str>) -> Self {
+        match value {
+            Some("eighths") => Self::Eighths,
+            _ => Self::Blocks,
+        }
+    }
+}
+
+/// Renders a percentage as a fixed-width bar in front of a segment's primary text
+#[derive(Debug, Clone)]
+pub struct ProgressBar {
+    width: usize,
+    /// Full cell; the style's default when unset
+    fill: Option<String>,
+    /// Empty cell; the style's default when unset
+    empty: Option<String>,
+    style: BarStyle,
+}
+
+impl Default for ProgressBar {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ProgressBar {
+    pub fn new() -> Self {
+        Self {
+            width: 10,
+            fill: None,
+            empty: None,
+            style: BarStyle::Blocks,
+        }
+    }
+
+    pub fn with_width(mut self, width: usize) -> Self {
+        self.width = width.max(1);
+        self
+    }
+
+    pub fn with_fill(mut self, fill: String) -> Self {
+        self.fill = Some(fill);
+        self
+    }
+
+    pub fn with_empty(mut self, empty: String) -> Self {
+        self.empty = Some(empty);
+        self
+    }
+
+    pub fn with_style(mut self, style: BarStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Build a bar from segment options when `progress_bar` is enabled
+    pub fn from_options(options: &HashMap<String, serde_json::Value>) -> Option<Self> {
+        let enabled = options
+            .get("progress_bar")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+        if !enabled {
+            return None;
+        }
+
+        let mut bar = Self::new().with_style(BarStyle::from_option(
+            options.get("bar_style").and_then(|v| v.as_str()),
+        ));
+        if let Some(width) = options.get("bar_width").and_then(|v| v.as_u64()) {
+            bar = bar.with_width(width as usize);
+        }
+        if let Some(fill) = options.get("bar_fill").and_then(|v| v.as_str()) {
+            bar = bar.with_fill(fill.to_string());
+        }
+        if let Some(empty) = options.get("bar_empty").and_then(|v| v.as_str()) {
+            bar = bar.with_empty(empty.to_string());
+        }
+        Some(bar)
+    }
+
+    /// Render `percent` (0-100, clamped) as a bar of `width` cells
+    pub fn render(&self, percent: f64) -> String {
+        let ratio = (percent / 100.0).clamp(0.0, 1.0);
+        let fill = self.fill.as_deref().unwrap_or(self.style.default_fill());
+        let empty = self.empty.as_deref().unwrap_or(self.style.default_empty());
+
+        match self.style {
+            BarStyle::Blocks => {
+                let filled = (ratio * self.width as f64).round() as usize;
+                format!(
+                    "{}{}",
+                    fill.repeat(filled),
+                    empty.repeat(self.width - filled)
+                )
+            }
+            BarStyle::Eighths => {
+                let eighths = (ratio * self.width as f64 * 8.0).round() as usize;
+                let full = eighths / 8;
+                let partial = eighths % 8;
+
+                let mut bar = fill.repeat(full);
+                let mut cells = full;
+                if partial > 0 {
+                    bar.push(EIGHTHS[partial - 1]);
+                    cells += 1;
+                }
+                bar.push_str(&empty.repeat(self.width - cells));
+                bar
+            }
+        }
+    }
+
+    /// Percentage published by a segment: `percentage`, or the first `*_utilization` key
+    pub fn percentage_from_metadata(metadata: &HashMap<String, String>) -> Option<f64> {
+        if let Some(percentage) = metadata.get("percentage").and_then(|v| v.parse().ok()) {
+            return Some(percentage);
+        }
+
+        let mut keys: Vec<&String> = metadata
+            .keys()
+            .filter(|key| key.ends_with("_utilization"))
+            .collect();
+        keys.sort();
+        keys.into_iter()
+            .find_map(|key| metadata.get(key).and_then(|v| v.parse().ok()))
+    }
+
+    /// Prefix the segment's primary text with the bar; segments without a percentage are left as is
+    pub fn apply(&self, data: &mut SegmentData) {
+        if let Some(percent) = Self::percentage_from_metadata(&data.metadata) {
+            data.primary = format!("{} {}", self.render(percent), data.primary);
+        }
+    }
+}
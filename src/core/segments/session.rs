@@ -10,7 +10,7 @@ impl SessionSegment {
         Self
     }
 
-    fn format_duration(ms: u64) -> String {
+    pub fn format_duration(ms: u64) -> String {
         if ms < 1000 {
             format!("{}ms", ms)
         } else if ms < 60_000 {
@@ -0,0 +1,59 @@
+use super::{Segment, SegmentData};
+use crate::config::{InputData, SegmentId};
+use std::collections::HashMap;
+use std::path::Path;
+
+pub struct SessionIdSegment {
+    length: usize,
+}
+
+impl Default for SessionIdSegment {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SessionIdSegment {
+    pub fn new() -> Self {
+        Self { length: 8 }
+    }
+
+    /// Characters of the id to show; 0 shows the full id
+    pub fn with_length(mut self, length: usize) -> Self {
+        self.length = length;
+        self
+    }
+}
+
+impl Segment for SessionIdSegment {
+    fn collect(&self, input: &InputData) -> Option<SegmentData> {
+        // Transcripts are named after their session, which covers inputs without session_id
+        let session_id = input.session_id.clone().or_else(|| {
+            Path::new(&input.transcript_path)
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().into_owned())
+        })?;
+        if session_id.is_empty() {
+            return None;
+        }
+
+        let primary = if self.length > 0 {
+            session_id.chars().take(self.length).collect()
+        } else {
+            session_id.clone()
+        };
+
+        let mut metadata = HashMap::new();
+        metadata.insert("session_id".to_string(), session_id);
+
+        Some(SegmentData {
+            primary,
+            secondary: String::new(),
+            metadata,
+        })
+    }
+
+    fn id(&self) -> SegmentId {
+        SegmentId::SessionId
+    }
+}
@@ -0,0 +1,221 @@
+use super::cost::CostSegment;
+use super::threshold_utils::{self, Thresholds};
+use super::{color_utils, Segment, SegmentData};
+use crate::config::{InputData, ModelConfig, SegmentId};
+use crate::core::spend;
+use chrono::{DateTime, Local, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Cached spend totals so the statusline doesn't rescan every transcript on each refresh
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SpendCache {
+    pub today_usd: f64,
+    pub week_usd: f64,
+    pub month_usd: f64,
+    /// Local date the totals belong to, invalidates the cache at midnight
+    pub day: String,
+    pub cached_at: String,
+}
+
+pub struct SpendSegment {
+    show_week: bool,
+    show_month: bool,
+    cache_duration: u64,
+    budget_usd: Option<f64>,
+    show_remaining: bool,
+    thresholds: Thresholds,
+}
+
+impl Default for SpendSegment {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SpendSegment {
+    pub fn new() -> Self {
+        Self {
+            show_week: true,
+            show_month: false,
+            cache_duration: 60,
+            budget_usd: None,
+            show_remaining: false,
+            thresholds: Thresholds::default(),
+        }
+    }
+
+    /// Warning and critical settings from the segment's options
+    pub fn with_thresholds(mut self, thresholds: Thresholds) -> Self {
+        self.thresholds = thresholds;
+        self
+    }
+
+    pub fn with_week(mut self, show_week: bool) -> Self {
+        self.show_week = show_week;
+        self
+    }
+
+    pub fn with_month(mut self, show_month: bool) -> Self {
+        self.show_month = show_month;
+        self
+    }
+
+    pub fn with_cache_duration(mut self, cache_duration: u64) -> Self {
+        self.cache_duration = cache_duration;
+        self
+    }
+
+    /// Daily budget in USD; thresholds then apply to the percent of today's budget spent
+    pub fn with_budget(mut self, budget_usd: Option<f64>) -> Self {
+        self.budget_usd = budget_usd.filter(|budget| *budget > 0.0);
+        self
+    }
+
+    pub fn with_remaining(mut self, show_remaining: bool) -> Self {
+        self.show_remaining = show_remaining;
+        self
+    }
+
+    pub fn get_cache_path() -> Option<std::path::PathBuf> {
+        let home = dirs::home_dir()?;
+        Some(
+            home.join(".claude")
+                .join("ccline")
+                .join(".spend_cache.json"),
+        )
+    }
+
+    fn load_cache(&self) -> Option<SpendCache> {
+        let content = std::fs::read_to_string(Self::get_cache_path()?).ok()?;
+        let cache: SpendCache = serde_json::from_str(&content).ok()?;
+
+        let cached_at = DateTime::parse_from_rfc3339(&cache.cached_at).ok()?;
+        let elapsed = Utc::now().signed_duration_since(cached_at.with_timezone(&Utc));
+        let today = Local::now().format("%Y-%m-%d").to_string();
+
+        if elapsed.num_seconds() < self.cache_duration as i64 && cache.day == today {
+            Some(cache)
+        } else {
+            None
+        }
+    }
+
+    fn save_cache(&self, cache: &SpendCache) {
+        if let Some(cache_path) = Self::get_cache_path() {
+            if let Some(parent) = cache_path.parent() {
+                let _ = std::fs::create_dir_all(parent);
+            }
+            // Write a temp file per process and rename it, so concurrent renders never
+            // read a partly written cache
+            if let Ok(json) = serde_json::to_string_pretty(cache) {
+                let temp_path =
+                    cache_path.with_extension(format!("json.{}.tmp", std::process::id()));
+                if std::fs::write(&temp_path, json).is_ok()
+                    && std::fs::rename(&temp_path, &cache_path).is_err()
+                {
+                    let _ = std::fs::remove_file(&temp_path);
+                }
+            }
+        }
+    }
+
+    /// Scan all project transcripts from the earliest period start
+    pub fn compute_totals() -> Option<SpendCache> {
+        let projects_dir = spend::claude_projects_dir()?;
+        let model_config = ModelConfig::load();
+
+        let day_start = spend::start_of_day();
+        let week_start = spend::start_of_week();
+        let month_start = spend::start_of_month();
+        let since = week_start.min(month_start);
+
+        let records = spend::collect_usage_records(&projects_dir, Some(since), &model_config);
+
+        Some(SpendCache {
+            today_usd: spend::total_cost_since(&records, day_start),
+            week_usd: spend::total_cost_since(&records, week_start),
+            month_usd: spend::total_cost_since(&records, month_start),
+            day: Local::now().format("%Y-%m-%d").to_string(),
+            cached_at: Utc::now().to_rfc3339(),
+        })
+    }
+
+    /// Load cached totals or rescan transcripts when the cache is stale
+    pub fn load_totals(&self) -> Option<SpendCache> {
+        if let Some(cache) = self.load_cache() {
+            return Some(cache);
+        }
+
+        let totals = Self::compute_totals()?;
+        self.save_cache(&totals);
+        Some(totals)
+    }
+}
+
+impl Segment for SpendSegment {
+    fn collect(&self, _input: &InputData) -> Option<SegmentData> {
+        let totals = self.load_totals()?;
+
+        let primary = format!("today {}", CostSegment::format_cost(totals.today_usd));
+
+        let mut periods = Vec::new();
+        if let Some(budget) = self.budget_usd.filter(|_| self.show_remaining) {
+            periods.push(threshold_utils::format_budget_remaining(
+                totals.today_usd,
+                budget,
+            ));
+        }
+        if self.show_week {
+            periods.push(format!(
+                "week {}",
+                CostSegment::format_cost(totals.week_usd)
+            ));
+        }
+        if self.show_month {
+            periods.push(format!(
+                "month {}",
+                CostSegment::format_cost(totals.month_usd)
+            ));
+        }
+        let secondary = if periods.is_empty() {
+            String::new()
+        } else {
+            format!("· {}", periods.join(" · "))
+        };
+
+        let mut metadata = HashMap::new();
+        metadata.insert("today_usd".to_string(), totals.today_usd.to_string());
+        metadata.insert("week_usd".to_string(), totals.week_usd.to_string());
+        metadata.insert("month_usd".to_string(), totals.month_usd.to_string());
+
+        if let Some(utilization) =
+            threshold_utils::budget_utilization(totals.today_usd, self.budget_usd)
+        {
+            metadata.insert("budget_usd".to_string(), self.budget_usd.unwrap_or_default().to_string());
+            metadata.insert("budget_utilization".to_string(), utilization.to_string());
+
+            // Check if we need to apply threshold-based color override
+            if let Some(color) = self.thresholds.color_for_utilization(utilization) {
+                // Serialize the color to JSON for metadata using shared helper
+                let color_json = color_utils::serialize_ansi_color_to_json(&color);
+                metadata.insert("text_color_override".to_string(), color_json);
+            }
+
+            // Check if we need to apply threshold-based bold override
+            if let Some(should_bold) = self.thresholds.bold_for_utilization(utilization) {
+                metadata.insert("text_bold_override".to_string(), should_bold.to_string());
+            }
+        }
+
+        Some(SegmentData {
+            primary,
+            secondary,
+            metadata,
+        })
+    }
+
+    fn id(&self) -> SegmentId {
+        SegmentId::Spend
+    }
+}
@@ -0,0 +1,191 @@
+use super::{Segment, SegmentData};
+use crate::config::{InputData, SegmentId};
+use crate::core::spend::format_tokens;
+use crate::core::transcript::{self, MessageDeduper};
+use crate::utils::text::truncate;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Task calls still waiting for their result, and what subagents have used so far
+#[derive(Debug, Default)]
+struct SubagentActivity {
+    /// Descriptions of running Task calls, oldest first
+    running: Vec<String>,
+    /// Subagent messages seen after the last main-chain entry
+    sidechain_active: bool,
+    tokens: u64,
+    output_tokens: u64,
+    messages: u64,
+}
+
+impl SubagentActivity {
+    fn load(path: &Path) -> Option<Self> {
+        let mut activity = Self::default();
+        let mut deduper = MessageDeduper::new();
+        // Task tool_use id -> description, in launch order
+        let mut pending: Vec<(String, String)> = Vec::new();
+
+        for entry in transcript::entries(path).ok()? {
+            if entry.is_sidechain {
+                activity.sidechain_active = true;
+                activity.add_usage(&entry, &mut deduper);
+                continue;
+            }
+            activity.sidechain_active = false;
+
+            for tool_use in entry.tool_uses().filter(|tool_use| tool_use.name == "Task") {
+                let field = |key: &str| tool_use.input.get(key).and_then(|v| v.as_str());
+                let description = field("description")
+                    .or_else(|| field("subagent_type"))
+                    .unwrap_or("Task")
+                    .to_string();
+                pending.push((tool_use.id.clone(), description));
+            }
+            for tool_result in entry.tool_results() {
+                pending.retain(|(id, _)| *id != tool_result.tool_use_id);
+            }
+        }
+
+        // Newer Claude Code versions write subagent messages to their own files
+        for agent_path in transcript::subagent_transcripts(path) {
+            if let Ok(entries) = transcript::entries(&agent_path) {
+                for entry in entries {
+                    activity.add_usage(&entry, &mut deduper);
+                }
+            }
+        }
+
+        activity.running = pending
+            .into_iter()
+            .map(|(_, description)| description)
+            .collect();
+        Some(activity)
+    }
+
+    fn add_usage(&mut self, entry: &transcript::Entry, deduper: &mut MessageDeduper) {
+        if !entry.is_assistant() || !deduper.first_seen(entry) {
+            return;
+        }
+        if let Some(usage) = entry.usage() {
+            self.tokens += usage.total_for_cost() as u64;
+            self.output_tokens += usage.output_tokens as u64;
+            self.messages += 1;
+        }
+    }
+
+    /// Running subagents; sidechain messages after the main thread's last entry count as one
+    /// when no Task call is pending
+    fn running_count(&self) -> usize {
+        if self.running.is_empty() && self.sidechain_active {
+            1
+        } else {
+            self.running.len()
+        }
+    }
+}
+
+pub struct SubagentsSegment {
+    show_descriptions: bool,
+    show_tokens: bool,
+    hide_when_idle: bool,
+    max_text_length: usize,
+}
+
+impl Default for SubagentsSegment {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SubagentsSegment {
+    pub fn new() -> Self {
+        Self {
+            show_descriptions: true,
+            show_tokens: true,
+            hide_when_idle: false,
+            max_text_length: 30,
+        }
+    }
+
+    /// Show the descriptions of running Task calls
+    pub fn with_descriptions(mut self, show_descriptions: bool) -> Self {
+        self.show_descriptions = show_descriptions;
+        self
+    }
+
+    /// Show the total tokens subagents have used in the session
+    pub fn with_tokens(mut self, show_tokens: bool) -> Self {
+        self.show_tokens = show_tokens;
+        self
+    }
+
+    /// Hide the segment while no subagent is running
+    pub fn with_hide_when_idle(mut self, hide_when_idle: bool) -> Self {
+        self.hide_when_idle = hide_when_idle;
+        self
+    }
+
+    pub fn with_max_text_length(mut self, max_text_length: usize) -> Self {
+        self.max_text_length = max_text_length.max(1);
+        self
+    }
+}
+
+impl Segment for SubagentsSegment {
+    fn collect(&self, input: &InputData) -> Option<SegmentData> {
+        let activity = SubagentActivity::load(Path::new(&input.transcript_path))?;
+        let running = activity.running_count();
+
+        // Nothing to report in sessions that never used a subagent
+        if running == 0 && (self.hide_when_idle || activity.messages == 0) {
+            return None;
+        }
+
+        let primary = if running == 0 {
+            "idle".to_string()
+        } else {
+            format!("{} running", running)
+        };
+
+        let mut details = Vec::new();
+        if self.show_descriptions && !activity.running.is_empty() {
+            let descriptions: Vec<String> = activity
+                .running
+                .iter()
+                .map(|description| truncate(description, self.max_text_length))
+                .collect();
+            details.push(descriptions.join(", "));
+        }
+        if self.show_tokens && activity.tokens > 0 {
+            details.push(format!("{} tokens", format_tokens(activity.tokens)));
+        }
+        let secondary = if details.is_empty() {
+            String::new()
+        } else {
+            format!("· {}", details.join(" · "))
+        };
+
+        let mut metadata = HashMap::new();
+        metadata.insert("running".to_string(), running.to_string());
+        metadata.insert("running_tasks".to_string(), activity.running.join("\n"));
+        metadata.insert("subagent_tokens".to_string(), activity.tokens.to_string());
+        metadata.insert(
+            "subagent_output_tokens".to_string(),
+            activity.output_tokens.to_string(),
+        );
+        metadata.insert(
+            "subagent_messages".to_string(),
+            activity.messages.to_string(),
+        );
+
+        Some(SegmentData {
+            primary,
+            secondary,
+            metadata,
+        })
+    }
+
+    fn id(&self) -> SegmentId {
+        SegmentId::Subagents
+    }
+}
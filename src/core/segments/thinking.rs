@@ -0,0 +1,135 @@
+use super::{Segment, SegmentData};
+use crate::config::{InputData, SegmentId};
+use crate::core::spend::format_tokens;
+use crate::core::transcript::{self, ContentBlock};
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+/// Rough characters per token for estimating thinking size from its text
+const CHARS_PER_TOKEN: u64 = 4;
+
+/// Thinking blocks in the assistant messages since the last user prompt
+#[derive(Debug, Default)]
+struct TurnThinking {
+    blocks: u64,
+    redacted_blocks: u64,
+    chars: u64,
+    /// Whether any assistant message was found for the turn
+    replied: bool,
+}
+
+impl TurnThinking {
+    fn load(path: &Path) -> Option<Self> {
+        let mut thinking = Self::default();
+        // Resumed sessions can repeat entries, so count each block of a message once
+        let mut seen_blocks = HashSet::new();
+
+        for entry in transcript::entries_rev(path).ok()? {
+            if entry.is_sidechain {
+                continue;
+            }
+            if entry.is_user_prompt() {
+                break;
+            }
+            if !entry.is_assistant() {
+                continue;
+            }
+            thinking.replied = true;
+
+            for block in entry.content_blocks() {
+                match block {
+                    ContentBlock::Thinking { thinking: text } => {
+                        if !seen_blocks.insert((entry.dedupe_key(), text.clone())) {
+                            continue;
+                        }
+                        thinking.blocks += 1;
+                        thinking.chars += text.chars().count() as u64;
+                    }
+                    ContentBlock::RedactedThinking {} => {
+                        thinking.blocks += 1;
+                        thinking.redacted_blocks += 1;
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        Some(thinking)
+    }
+
+    fn used(&self) -> bool {
+        self.blocks > 0
+    }
+
+    fn estimated_tokens(&self) -> u64 {
+        self.chars / CHARS_PER_TOKEN
+    }
+}
+
+pub struct ThinkingSegment {
+    hide_when_off: bool,
+}
+
+impl Default for ThinkingSegment {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ThinkingSegment {
+    pub fn new() -> Self {
+        Self {
+            hide_when_off: false,
+        }
+    }
+
+    /// Hide the segment for turns without thinking
+    pub fn with_hide_when_off(mut self, hide_when_off: bool) -> Self {
+        self.hide_when_off = hide_when_off;
+        self
+    }
+}
+
+impl Segment for ThinkingSegment {
+    fn collect(&self, input: &InputData) -> Option<SegmentData> {
+        let thinking = TurnThinking::load(Path::new(&input.transcript_path))?;
+        if !thinking.replied || (self.hide_when_off && !thinking.used()) {
+            return None;
+        }
+
+        let tokens = thinking.estimated_tokens();
+        let primary = if !thinking.used() {
+            "off".to_string()
+        } else if tokens > 0 {
+            format!("~{} tokens", format_tokens(tokens))
+        } else {
+            // Redacted or empty thinking text: used, size unknown
+            "on".to_string()
+        };
+        let secondary = if thinking.blocks > 1 {
+            format!("· {} blocks", thinking.blocks)
+        } else {
+            String::new()
+        };
+
+        let mut metadata = HashMap::new();
+        metadata.insert("thinking_used".to_string(), thinking.used().to_string());
+        metadata.insert("thinking_blocks".to_string(), thinking.blocks.to_string());
+        metadata.insert(
+            "redacted_blocks".to_string(),
+            thinking.redacted_blocks.to_string(),
+        );
+        metadata.insert("thinking_chars".to_string(), thinking.chars.to_string());
+        metadata.insert("thinking_tokens_estimate".to_string(), tokens.to_string());
+
+        Some(SegmentData {
+            primary,
+            secondary,
+            metadata,
+        })
+    }
+
+    fn id(&self) -> SegmentId {
+        SegmentId::Thinking
+    }
+}
@@ -1,114 +1,126 @@
-use crate::config::{AnsiColor, Config, SegmentId};
-use once_cell::sync::OnceCell;
-use std::sync::Mutex;
+use crate::config::{AnsiColor, SegmentConfig};
 
-/// Cache for loaded config to avoid repeated disk I/O
-static CONFIG_CACHE: OnceCell<Mutex<Option<Config>>> = OnceCell::new();
-
-/// Load config with caching to avoid repeated disk reads
-fn get_cached_config() -> Option<Config> {
-    let cache = CONFIG_CACHE.get_or_init(|| Mutex::new(None));
-    let mut cache_guard = cache.lock().ok()?;
+/// Express spend as a percentage of a budget so it can share the percentage thresholds
+/// Returns None when no positive budget is set
+pub fn budget_utilization(spent_usd: f64, budget_usd: Option<f64>) -> Option<f64> {
+    budget_usd
+        .filter(|budget| *budget > 0.0)
+        .map(|budget| spent_usd / budget * 100.0)
+}
 
-    if cache_guard.is_none() {
-        *cache_guard = Config::load().ok();
+/// Format what is left of a budget, e.g. "$3.20 left" or "$1.10 over"
+pub fn format_budget_remaining(spent_usd: f64, budget_usd: f64) -> String {
+    let remaining = budget_usd - spent_usd;
+    if remaining >= 0.0 {
+        format!("${:.2} left", remaining)
+    } else {
+        format!("${:.2} over", -remaining)
     }
+}
 
-    cache_guard.clone()
+/// Which threshold a value has crossed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThresholdLevel {
+    Warning,
+    Critical,
 }
 
-/// Helper to get warning and critical thresholds for a segment
-pub fn get_thresholds_for_segment(segment_id: SegmentId) -> Option<(f64, f64)> {
-    let config = get_cached_config()?;
-    let segment_config = config.segments.iter().find(|s| s.id == segment_id)?;
+/// Warning and critical settings of a segment, read from the options of the config
+/// being rendered so overrides (`--theme`, `ccline render --config`) apply
+#[derive(Debug, Clone)]
+pub struct Thresholds {
+    pub warning: f64,
+    pub critical: f64,
+    pub warning_color: Option<AnsiColor>,
+    pub critical_color: Option<AnsiColor>,
+    pub warning_bold: Option<bool>,
+    pub critical_bold: Option<bool>,
+}
 
-    let warning_threshold = segment_config
-        .options
-        .get("warning_threshold")
-        .and_then(|v| v.as_u64())
-        .unwrap_or(60) as f64;
+impl Default for Thresholds {
+    fn default() -> Self {
+        Self {
+            warning: 60.0,
+            critical: 80.0,
+            warning_color: None,
+            critical_color: None,
+            warning_bold: None,
+            critical_bold: None,
+        }
+    }
+}
 
-    let critical_threshold = segment_config
-        .options
-        .get("critical_threshold")
-        .and_then(|v| v.as_u64())
-        .unwrap_or(80) as f64;
+impl Thresholds {
+    /// Read `warning_threshold`, `critical_threshold` and the matching color and bold options
+    pub fn from_config(segment_config: &SegmentConfig) -> Self {
+        let options = &segment_config.options;
+        let defaults = Self::default();
+        let threshold = |key: &str, default: f64| {
+            options
+                .get(key)
+                .and_then(|v| v.as_u64())
+                .map(|v| v as f64)
+                .unwrap_or(default)
+        };
 
-    Some((warning_threshold, critical_threshold))
-}
+        Self {
+            warning: threshold("warning_threshold", defaults.warning),
+            critical: threshold("critical_threshold", defaults.critical),
+            warning_color: options.get("warning_color").and_then(parse_threshold_color),
+            critical_color: options
+                .get("critical_color")
+                .and_then(parse_threshold_color),
+            warning_bold: options.get("warning_bold").and_then(|v| v.as_bool()),
+            critical_bold: options.get("critical_bold").and_then(|v| v.as_bool()),
+        }
+    }
 
-/// Get color override based on utilization percentage
-pub fn get_color_for_utilization(segment_id: SegmentId, utilization: f64) -> Option<AnsiColor> {
-    let config = get_cached_config()?;
-    let segment_config = config.segments.iter().find(|s| s.id == segment_id)?;
-    let (warning_threshold, critical_threshold) = get_thresholds_for_segment(segment_id)?;
+    /// Threshold crossed by a utilization percentage, None below the warning threshold
+    pub fn level_for_utilization(&self, utilization: f64) -> Option<ThresholdLevel> {
+        if utilization >= self.critical {
+            Some(ThresholdLevel::Critical)
+        } else if utilization >= self.warning {
+            Some(ThresholdLevel::Warning)
+        } else {
+            None
+        }
+    }
 
-    // Determine which color to use based on utilization
-    if utilization >= critical_threshold {
-        // Critical threshold exceeded - use critical color
-        segment_config
-            .options
-            .get("critical_color")
-            .and_then(|v| {
-                if let Some(c256) = v.get("c256").and_then(|c| c.as_u64()) {
-                    Some(AnsiColor::Color256 { c256: c256 as u8 })
-                } else if let Some(c16) = v.get("c16").and_then(|c| c.as_u64()) {
-                    Some(AnsiColor::Color16 { c16: c16 as u8 })
-                } else {
-                    None
-                }
-            })
-    } else if utilization >= warning_threshold {
-        // Warning threshold exceeded - use warning color
-        segment_config
-            .options
-            .get("warning_color")
-            .and_then(|v| {
-                if let Some(c256) = v.get("c256").and_then(|c| c.as_u64()) {
-                    Some(AnsiColor::Color256 { c256: c256 as u8 })
-                } else if let Some(c16) = v.get("c16").and_then(|c| c.as_u64()) {
-                    Some(AnsiColor::Color16 { c16: c16 as u8 })
-                } else {
-                    None
-                }
-            })
-    } else {
-        // Below warning threshold - use default color
-        None
+    /// Get color override based on utilization percentage
+    pub fn color_for_utilization(&self, utilization: f64) -> Option<AnsiColor> {
+        self.color_for_level(self.level_for_utilization(utilization)?)
     }
-}
 
-/// Check if text should be bold based on utilization percentage
-pub fn should_be_bold(segment_id: SegmentId, utilization: f64) -> Option<bool> {
-    let config = get_cached_config()?;
-    let segment_config = config.segments.iter().find(|s| s.id == segment_id)?;
-    let (warning_threshold, critical_threshold) = get_thresholds_for_segment(segment_id)?;
+    /// Check if text should be bold based on utilization percentage
+    pub fn bold_for_utilization(&self, utilization: f64) -> Option<bool> {
+        self.bold_for_level(self.level_for_utilization(utilization)?)
+    }
 
-    // Determine if text should be bold based on utilization
-    if utilization >= critical_threshold {
-        // Critical threshold - check critical_bold option
-        segment_config
-            .options
-            .get("critical_bold")
-            .and_then(|v| v.as_bool())
-    } else if utilization >= warning_threshold {
-        // Warning threshold - check warning_bold option
-        segment_config
-            .options
-            .get("warning_bold")
-            .and_then(|v| v.as_bool())
-    } else {
-        // Below warning threshold - no bold override
-        None
+    /// Get the configured warning or critical color, for segments that decide the level themselves
+    pub fn color_for_level(&self, level: ThresholdLevel) -> Option<AnsiColor> {
+        match level {
+            ThresholdLevel::Critical => self.critical_color.clone(),
+            ThresholdLevel::Warning => self.warning_color.clone(),
+        }
     }
-}
 
-/// Invalidate the config cache (useful for tests or when config changes)
-#[allow(dead_code)]
-pub fn invalidate_config_cache() {
-    if let Some(cache) = CONFIG_CACHE.get() {
-        if let Ok(mut cache_guard) = cache.lock() {
-            *cache_guard = None;
+    /// Get the configured warning or critical bold option
+    pub fn bold_for_level(&self, level: ThresholdLevel) -> Option<bool> {
+        match level {
+            ThresholdLevel::Critical => self.critical_bold,
+            ThresholdLevel::Warning => self.warning_bold,
         }
     }
 }
+
+/// Parse a threshold color option ({"c256": n} or {"c16": n})
+fn parse_threshold_color(value: &serde_json::Value) -> Option<AnsiColor> {
+    if let Some(c256) = value.get("c256").and_then(|c| c.as_u64()) {
+        Some(AnsiColor::Color256 { c256: c256 as u8 })
+    } else {
+        value
+            .get("c16")
+            .and_then(|c| c.as_u64())
+            .map(|c16| AnsiColor::Color16 { c16: c16 as u8 })
+    }
+}
@@ -0,0 +1,77 @@
+use super::{Segment, SegmentData};
+use crate::config::{InputData, SegmentId};
+use crate::utils::TimeFormatter;
+use chrono::{Local, Utc};
+use chrono_tz::Tz;
+use std::collections::HashMap;
+
+#[derive(Default)]
+pub struct TimeSegment {
+    formatter: TimeFormatter,
+    format: Option<String>,
+    timezone: Option<String>,
+}
+
+impl TimeSegment {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_formatter(mut self, formatter: TimeFormatter) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Custom strftime-style pattern, e.g. "%a %H:%M"
+    pub fn with_format(mut self, format: Option<String>) -> Self {
+        self.format = format.filter(|f| !f.is_empty());
+        self
+    }
+
+    /// IANA timezone name, e.g. "Asia/Shanghai"; local time is used when unset or invalid
+    pub fn with_timezone(mut self, timezone: Option<String>) -> Self {
+        self.timezone = timezone.filter(|tz| !tz.is_empty());
+        self
+    }
+}
+
+impl Segment for TimeSegment {
+    fn collect(&self, _input: &InputData) -> Option<SegmentData> {
+        let pattern = self
+            .format
+            .as_deref()
+            .unwrap_or_else(|| self.formatter.time_pattern());
+
+        let now = Utc::now();
+        let tz = self
+            .timezone
+            .as_deref()
+            .and_then(|name| name.parse::<Tz>().ok());
+
+        let (primary, timezone_name) = match tz {
+            Some(tz) => (
+                self.formatter.format(&now.with_timezone(&tz), pattern),
+                tz.name().to_string(),
+            ),
+            None => (
+                self.formatter.format(&now.with_timezone(&Local), pattern),
+                "local".to_string(),
+            ),
+        };
+
+        let mut metadata = HashMap::new();
+        metadata.insert("time".to_string(), primary.clone());
+        metadata.insert("timezone".to_string(), timezone_name);
+        metadata.insert("timestamp".to_string(), now.to_rfc3339());
+
+        Some(SegmentData {
+            primary,
+            secondary: String::new(),
+            metadata,
+        })
+    }
+
+    fn id(&self) -> SegmentId {
+        SegmentId::Time
+    }
+}
@@ -0,0 +1,138 @@
+use super::{Segment, SegmentData};
+use crate::config::{InputData, SegmentId};
+use crate::core::transcript;
+use crate::utils::text::truncate;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// One item of a TodoWrite list
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TodoItem {
+    #[serde(default)]
+    content: String,
+    /// "pending", "in_progress" or "completed"
+    #[serde(default)]
+    status: String,
+    /// Present-tense wording shown while the item is in progress, e.g. "Running tests"
+    active_form: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct TodoList {
+    #[serde(default)]
+    todos: Vec<TodoItem>,
+}
+
+impl TodoList {
+    /// The newest TodoWrite list of the main conversation, read from the end of the transcript
+    fn latest(path: &Path) -> Option<Self> {
+        transcript::entries_rev(path)
+            .ok()?
+            .filter(|entry| entry.is_assistant() && !entry.is_sidechain)
+            .find_map(|entry| {
+                entry
+                    .tool_uses()
+                    .filter(|tool_use| tool_use.name == "TodoWrite")
+                    .last()
+                    .and_then(|tool_use| serde_json::from_value(tool_use.input.clone()).ok())
+            })
+    }
+
+    fn count(&self, status: &str) -> usize {
+        self.todos
+            .iter()
+            .filter(|todo| todo.status == status)
+            .count()
+    }
+
+    /// Text of the item being worked on
+    fn current(&self) -> Option<&str> {
+        let todo = self
+            .todos
+            .iter()
+            .find(|todo| todo.status == "in_progress")?;
+        Some(
+            todo.active_form
+                .as_deref()
+                .filter(|text| !text.is_empty())
+                .unwrap_or(&todo.content),
+        )
+    }
+}
+
+pub struct TodosSegment {
+    show_current: bool,
+    max_text_length: usize,
+}
+
+impl Default for TodosSegment {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TodosSegment {
+    pub fn new() -> Self {
+        Self {
+            show_current: true,
+            max_text_length: 40,
+        }
+    }
+
+    /// Show the in-progress item after the count
+    pub fn with_current(mut self, show_current: bool) -> Self {
+        self.show_current = show_current;
+        self
+    }
+
+    pub fn with_max_text_length(mut self, max_text_length: usize) -> Self {
+        self.max_text_length = max_text_length.max(1);
+        self
+    }
+}
+
+impl Segment for TodosSegment {
+    fn collect(&self, input: &InputData) -> Option<SegmentData> {
+        let list = TodoList::latest(Path::new(&input.transcript_path))?;
+        let total = list.todos.len();
+        if total == 0 {
+            return None;
+        }
+
+        let completed = list.count("completed");
+        let percentage = completed as f64 / total as f64 * 100.0;
+
+        let secondary = match list.current() {
+            Some(current) if self.show_current => {
+                format!("· {}", truncate(current, self.max_text_length))
+            }
+            _ => String::new(),
+        };
+
+        let mut metadata = HashMap::new();
+        metadata.insert("total".to_string(), total.to_string());
+        metadata.insert("completed".to_string(), completed.to_string());
+        metadata.insert(
+            "in_progress".to_string(),
+            list.count("in_progress").to_string(),
+        );
+        metadata.insert("pending".to_string(), list.count("pending").to_string());
+        // Read by the shared progress_bar option
+        metadata.insert("percentage".to_string(), percentage.to_string());
+        if let Some(current) = list.current() {
+            metadata.insert("current".to_string(), current.to_string());
+        }
+
+        Some(SegmentData {
+            primary: format!("{}/{}", completed, total),
+            secondary,
+            metadata,
+        })
+    }
+
+    fn id(&self) -> SegmentId {
+        SegmentId::Todos
+    }
+}
@@ -0,0 +1,218 @@
+use super::{Segment, SegmentData};
+use crate::config::{InputData, SegmentId};
+use crate::core::transcript::{self, ToolUse};
+use crate::utils::text::truncate;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+/// Tool calls and failures seen in a transcript
+#[derive(Debug, Default)]
+struct ToolActivity {
+    counts: HashMap<String, u64>,
+    total: u64,
+    failed: u64,
+    last_tool: Option<String>,
+    last_target: Option<String>,
+}
+
+impl ToolActivity {
+    fn load(path: &Path) -> Option<Self> {
+        let mut activity = Self::default();
+        // Resumed sessions repeat earlier entries, so count each tool_use id once
+        let mut seen_uses = HashSet::new();
+        let mut seen_results = HashSet::new();
+
+        for entry in transcript::entries(path).ok()? {
+            for tool_use in entry.tool_uses() {
+                if !tool_use.id.is_empty() && !seen_uses.insert(tool_use.id.clone()) {
+                    continue;
+                }
+                *activity.counts.entry(tool_use.name.clone()).or_insert(0) += 1;
+                activity.total += 1;
+                activity.last_tool = Some(tool_use.name.clone());
+                activity.last_target = tool_target(tool_use);
+            }
+
+            for tool_result in entry.tool_results() {
+                if !tool_result.tool_use_id.is_empty()
+                    && !seen_results.insert(tool_result.tool_use_id.clone())
+                {
+                    continue;
+                }
+                if tool_result.is_error {
+                    activity.failed += 1;
+                }
+            }
+        }
+
+        Some(activity)
+    }
+
+    /// Tools by call count, most used first, ties by name
+    fn ranked(&self) -> Vec<(&str, u64)> {
+        let mut ranked: Vec<(&str, u64)> = self
+            .counts
+            .iter()
+            .map(|(name, count)| (name.as_str(), *count))
+            .collect();
+        ranked.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+        ranked
+    }
+}
+
+/// What a tool call acted on: a file name, a command, a pattern or a URL
+fn tool_target(tool_use: &ToolUse) -> Option<String> {
+    let input = &tool_use.input;
+    let field = |key: &str| input.get(key).and_then(|v| v.as_str());
+
+    if let Some(path) = field("file_path").or_else(|| field("notebook_path")) {
+        let name = Path::new(path)
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_else(|| path.to_string());
+        return Some(name);
+    }
+
+    // Only the first line of multi-line commands
+    ["command", "pattern", "url", "query", "description", "path"]
+        .into_iter()
+        .find_map(field)
+        .and_then(|value| value.lines().next())
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+pub struct ToolActivitySegment {
+    show_last: bool,
+    show_total: bool,
+    show_by_tool: bool,
+    show_errors: bool,
+    top_tools: usize,
+    max_target_length: usize,
+}
+
+impl Default for ToolActivitySegment {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ToolActivitySegment {
+    pub fn new() -> Self {
+        Self {
+            show_last: true,
+            show_total: true,
+            show_by_tool: true,
+            show_errors: true,
+            top_tools: 3,
+            max_target_length: 30,
+        }
+    }
+
+    /// Show the last tool used and its target, e.g. "Bash cargo test"
+    pub fn with_last(mut self, show_last: bool) -> Self {
+        self.show_last = show_last;
+        self
+    }
+
+    /// Show the total number of tool calls
+    pub fn with_total(mut self, show_total: bool) -> Self {
+        self.show_total = show_total;
+        self
+    }
+
+    /// Show the most used tools with their call counts
+    pub fn with_by_tool(mut self, show_by_tool: bool, top_tools: usize) -> Self {
+        self.show_by_tool = show_by_tool;
+        self.top_tools = top_tools;
+        self
+    }
+
+    /// Show the number of failed tool results
+    pub fn with_errors(mut self, show_errors: bool) -> Self {
+        self.show_errors = show_errors;
+        self
+    }
+
+    pub fn with_max_target_length(mut self, max_target_length: usize) -> Self {
+        self.max_target_length = max_target_length.max(1);
+        self
+    }
+}
+
+impl Segment for ToolActivitySegment {
+    fn collect(&self, input: &InputData) -> Option<SegmentData> {
+        let activity = ToolActivity::load(Path::new(&input.transcript_path))?;
+        if activity.total == 0 {
+            return None;
+        }
+
+        let ranked = activity.ranked();
+
+        let mut parts = Vec::new();
+        if self.show_last {
+            if let Some(tool) = &activity.last_tool {
+                match &activity.last_target {
+                    Some(target) => parts.push(format!(
+                        "{} {}",
+                        tool,
+                        truncate(target, self.max_target_length)
+                    )),
+                    None => parts.push(tool.clone()),
+                }
+            }
+        }
+        if self.show_total {
+            parts.push(format!("{} calls", activity.total));
+        }
+        if self.show_by_tool && self.top_tools > 0 {
+            let top: Vec<String> = ranked
+                .iter()
+                .take(self.top_tools)
+                .map(|(name, count)| format!("{} {}", name, count))
+                .collect();
+            parts.push(top.join(" "));
+        }
+        if self.show_errors && activity.failed > 0 {
+            parts.push(format!("{} failed", activity.failed));
+        }
+
+        if parts.is_empty() {
+            return None;
+        }
+        let primary = parts.remove(0);
+        let secondary = if parts.is_empty() {
+            String::new()
+        } else {
+            format!("· {}", parts.join(" · "))
+        };
+
+        let mut metadata = HashMap::new();
+        metadata.insert("total_calls".to_string(), activity.total.to_string());
+        metadata.insert("failed_calls".to_string(), activity.failed.to_string());
+        if let Some(tool) = &activity.last_tool {
+            metadata.insert("last_tool".to_string(), tool.clone());
+        }
+        if let Some(target) = &activity.last_target {
+            metadata.insert("last_target".to_string(), target.clone());
+        }
+        let counts: serde_json::Map<String, serde_json::Value> = ranked
+            .iter()
+            .map(|(name, count)| (name.to_string(), (*count).into()))
+            .collect();
+        metadata.insert(
+            "tool_counts".to_string(),
+            serde_json::Value::Object(counts).to_string(),
+        );
+
+        Some(SegmentData {
+            primary,
+            secondary,
+            metadata,
+        })
+    }
+
+    fn id(&self) -> SegmentId {
+        SegmentId::ToolActivity
+    }
+}
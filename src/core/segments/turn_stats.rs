@@ -0,0 +1,180 @@
+use super::{Segment, SegmentData, SessionSegment};
+use crate::config::{InputData, SegmentId};
+use crate::core::transcript::{self, MessageDeduper};
+use crate::utils::TimeFormatter;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::path::Path;
+
+const DEFAULT_FORMAT: &str = "{prompts} · {avg_latency}/turn · {since_prompt} ago";
+
+/// Turn counts and timings of the main conversation
+#[derive(Debug, Default)]
+struct TurnStats {
+    user_turns: u64,
+    assistant_turns: u64,
+    /// Sum and count of delays between a prompt or tool result and the reply to it
+    latency_ms: u64,
+    latency_samples: u64,
+    last_prompt: Option<DateTime<Utc>>,
+}
+
+impl TurnStats {
+    fn load(path: &Path) -> Option<Self> {
+        let mut stats = Self::default();
+        let mut deduper = MessageDeduper::new();
+        // When the assistant was last handed the turn, by a prompt or a tool result
+        let mut waiting_since: Option<DateTime<Utc>> = None;
+
+        for entry in transcript::entries(path).ok()? {
+            if entry.is_sidechain {
+                continue;
+            }
+
+            if entry.is_assistant() {
+                // Streaming writes one entry per block; only the first marks the reply
+                if !deduper.first_seen(&entry) {
+                    continue;
+                }
+                stats.assistant_turns += 1;
+                if let (Some(since), Some(time)) = (waiting_since.take(), entry.time()) {
+                    if let Ok(latency) = (time - since).to_std() {
+                        stats.latency_ms += latency.as_millis() as u64;
+                        stats.latency_samples += 1;
+                    }
+                }
+                continue;
+            }
+
+            if entry.is_user_prompt() {
+                stats.user_turns += 1;
+                stats.last_prompt = entry.time().or(stats.last_prompt);
+            }
+            if entry.is_user_prompt() || entry.tool_results().next().is_some() {
+                waiting_since = entry.time();
+            }
+        }
+
+        Some(stats)
+    }
+
+    fn average_latency_ms(&self) -> Option<u64> {
+        (self.latency_samples > 0).then(|| self.latency_ms / self.latency_samples)
+    }
+}
+
+pub struct TurnStatsSegment {
+    format: String,
+}
+
+impl Default for TurnStatsSegment {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TurnStatsSegment {
+    pub fn new() -> Self {
+        Self {
+            format: DEFAULT_FORMAT.to_string(),
+        }
+    }
+
+    /// Template with `{prompts}`, `{user_turns}`, `{assistant_turns}`, `{avg_latency}`,
+    /// `{since_prompt}` and `{api_ratio}` fields, plus `{input.<field>}` input fields
+    pub fn with_format(mut self, format: Option<String>) -> Self {
+        if let Some(format) = format.filter(|f| !f.is_empty()) {
+            self.format = format;
+        }
+        self
+    }
+
+    fn render(&self, input: &InputData, fields: &[(&str, String)]) -> String {
+        let text = fields
+            .iter()
+            .fold(self.format.clone(), |text, (name, value)| {
+                text.replace(&format!("{{{}}}", name), value)
+            });
+        input.render_template(&text)
+    }
+}
+
+impl Segment for TurnStatsSegment {
+    fn collect(&self, input: &InputData) -> Option<SegmentData> {
+        let stats = TurnStats::load(Path::new(&input.transcript_path))?;
+        if stats.user_turns == 0 && stats.assistant_turns == 0 {
+            return None;
+        }
+
+        let average_latency = stats.average_latency_ms();
+        let since_prompt = stats.last_prompt.map(|time| Utc::now() - time);
+
+        // Share of wall time spent waiting on the API, from Claude Code's own counters
+        let api_ratio = input.cost.as_ref().and_then(|cost| {
+            let total = cost.total_duration_ms.filter(|ms| *ms > 0)?;
+            Some(cost.total_api_duration_ms? as f64 / total as f64 * 100.0)
+        });
+
+        let fields = [
+            (
+                "prompts",
+                match stats.user_turns {
+                    1 => "1 prompt".to_string(),
+                    count => format!("{} prompts", count),
+                },
+            ),
+            ("user_turns", stats.user_turns.to_string()),
+            ("assistant_turns", stats.assistant_turns.to_string()),
+            (
+                "avg_latency",
+                average_latency
+                    .map(SessionSegment::format_duration)
+                    .unwrap_or_else(|| "-".to_string()),
+            ),
+            (
+                "since_prompt",
+                since_prompt
+                    .map(TimeFormatter::format_countdown)
+                    .unwrap_or_else(|| "-".to_string()),
+            ),
+            (
+                "api_ratio",
+                api_ratio
+                    .map(|ratio| format!("{:.0}%", ratio))
+                    .unwrap_or_else(|| "-".to_string()),
+            ),
+        ];
+
+        let mut metadata = HashMap::new();
+        metadata.insert("user_turns".to_string(), stats.user_turns.to_string());
+        metadata.insert(
+            "assistant_turns".to_string(),
+            stats.assistant_turns.to_string(),
+        );
+        if let Some(latency) = average_latency {
+            metadata.insert("avg_latency_ms".to_string(), latency.to_string());
+        }
+        if let Some(time) = stats.last_prompt {
+            metadata.insert("last_prompt_at".to_string(), time.to_rfc3339());
+        }
+        if let Some(since) = since_prompt {
+            metadata.insert(
+                "seconds_since_prompt".to_string(),
+                since.num_seconds().max(0).to_string(),
+            );
+        }
+        if let Some(ratio) = api_ratio {
+            metadata.insert("api_ratio".to_string(), ratio.to_string());
+        }
+
+        Some(SegmentData {
+            primary: self.render(input, &fields),
+            secondary: String::new(),
+            metadata,
+        })
+    }
+
+    fn id(&self) -> SegmentId {
+        SegmentId::TurnStats
+    }
+}
@@ -1,7 +1,7 @@
 use super::{Segment, SegmentData};
 use crate::config::{InputData, SegmentId};
-use crate::utils::credentials;
-use chrono::{DateTime, Datelike, Duration, Local, Timelike, Utc};
+use crate::utils::{credentials, TimeFormatter};
+use chrono::{DateTime, Local, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -26,12 +26,44 @@ pub struct ApiUsageCache {
     pub cached_at: String,
 }
 
+/// How usage segments display their reset time
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ResetFormat {
+    /// Wall-clock reset time, e.g. "→ 5pm"
+    #[default]
+    Absolute,
+    /// Time remaining until reset, e.g. "in 2h13m"
+    Countdown,
+}
+
+impl ResetFormat {
+    pub fn from_option(value: Option<&str>) -> Self {
+        match value {
+            Some("countdown") => Self::Countdown,
+            _ => Self::Absolute,
+        }
+    }
+}
+
 #[derive(Default)]
-pub struct UsageSegment;
+pub struct UsageSegment {
+    formatter: TimeFormatter,
+    reset_format: ResetFormat,
+}
 
 impl UsageSegment {
     pub fn new() -> Self {
-        Self
+        Self::default()
+    }
+
+    pub fn with_formatter(mut self, formatter: TimeFormatter) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    pub fn with_reset_format(mut self, reset_format: ResetFormat) -> Self {
+        self.reset_format = reset_format;
+        self
     }
 
     pub fn get_circle_icon(utilization: f64) -> String {
@@ -48,94 +80,59 @@ impl UsageSegment {
         }
     }
 
-    fn format_reset_time(reset_time_str: Option<&str>) -> String {
-        if let Some(time_str) = reset_time_str {
-            if let Ok(dt) = DateTime::parse_from_rfc3339(time_str) {
-                let mut local_dt = dt.with_timezone(&Local);
-                if local_dt.minute() > 45 {
-                    local_dt = local_dt + Duration::hours(1);
-                }
-                return format!(
-                    "{}-{}-{}",
-                    local_dt.month(),
-                    local_dt.day(),
-                    local_dt.hour()
-                );
-            }
+    /// Format 5-hour reset time as "11am" or "5pm" (or "17:00" in 24h mode)
+    pub fn format_5hour_reset_time(
+        reset_time_str: Option<&str>,
+        formatter: &TimeFormatter,
+    ) -> String {
+        match TimeFormatter::parse_local(reset_time_str) {
+            Some(dt) => formatter.format_hour(&TimeFormatter::round_to_hour(dt)),
+            None => "?".to_string(),
         }
-        "?".to_string()
     }
 
-    /// Format 5-hour reset time as "11am" or "5pm"
-    pub fn format_5hour_reset_time(reset_time_str: Option<&str>) -> String {
-        if let Some(time_str) = reset_time_str {
-            if let Ok(dt) = DateTime::parse_from_rfc3339(time_str) {
-                let mut local_dt = dt.with_timezone(&Local);
-                // Round up if more than 45 minutes past the hour
-                if local_dt.minute() > 45 {
-                    local_dt = local_dt + Duration::hours(1);
-                }
-                let hour = local_dt.hour();
-                let (hour_12, period) = if hour == 0 {
-                    (12, "am")
-                } else if hour < 12 {
-                    (hour, "am")
-                } else if hour == 12 {
-                    (12, "pm")
-                } else {
-                    (hour - 12, "pm")
-                };
-                return format!("{}{}", hour_12, period);
-            }
+    /// Format 7-day reset time as "Oct 9, 5am" (or "Oct 9, 05:00" in 24h mode)
+    pub fn format_7day_reset_time(
+        reset_time_str: Option<&str>,
+        formatter: &TimeFormatter,
+    ) -> String {
+        match TimeFormatter::parse_local(reset_time_str) {
+            Some(dt) => formatter.format_date_hour(&TimeFormatter::round_to_hour(dt)),
+            None => "?".to_string(),
         }
-        "?".to_string()
     }
 
-    /// Format 7-day reset time as "Oct 9, 5am"
-    pub fn format_7day_reset_time(reset_time_str: Option<&str>) -> String {
-        if let Some(time_str) = reset_time_str {
-            if let Ok(dt) = DateTime::parse_from_rfc3339(time_str) {
-                let mut local_dt = dt.with_timezone(&Local);
-                // Round up if more than 45 minutes past the hour
-                if local_dt.minute() > 45 {
-                    local_dt = local_dt + Duration::hours(1);
-                }
-                let month_name = match local_dt.month() {
-                    1 => "Jan",
-                    2 => "Feb",
-                    3 => "Mar",
-                    4 => "Apr",
-                    5 => "May",
-                    6 => "Jun",
-                    7 => "Jul",
-                    8 => "Aug",
-                    9 => "Sep",
-                    10 => "Oct",
-                    11 => "Nov",
-                    12 => "Dec",
-                    _ => "?",
-                };
-                let hour = local_dt.hour();
-                let (hour_12, period) = if hour == 0 {
-                    (12, "am")
-                } else if hour < 12 {
-                    (hour, "am")
-                } else if hour == 12 {
-                    (12, "pm")
-                } else {
-                    (hour - 12, "pm")
-                };
-                // Format as "Oct 9, 5am"
-                return format!(
-                    "{} {}, {}{}",
-                    month_name,
-                    local_dt.day(),
-                    hour_12,
-                    period
-                );
-            }
+    /// Format the reset time as a countdown ("in 2h13m")
+    pub fn format_reset_countdown(reset_time_str: Option<&str>) -> String {
+        match TimeFormatter::parse_local(reset_time_str) {
+            Some(dt) => format!(
+                "in {}",
+                TimeFormatter::format_countdown(dt.signed_duration_since(Local::now()))
+            ),
+            None => "?".to_string(),
+        }
+    }
+
+    /// Whether the reset time has already passed, meaning the cached value is stale
+    pub fn is_reset_passed(reset_time_str: Option<&str>) -> bool {
+        TimeFormatter::parse_local(reset_time_str)
+            .map(|dt| dt <= Local::now())
+            .unwrap_or(false)
+    }
+
+    /// Build the secondary reset text shared by the usage segments
+    pub fn format_reset_display(
+        reset_time_str: Option<&str>,
+        reset_format: ResetFormat,
+        absolute: impl FnOnce(Option<&str>) -> String,
+    ) -> String {
+        let stale = Self::is_reset_passed(reset_time_str);
+        match (reset_format, stale) {
+            (ResetFormat::Absolute, false) => format!("→ {}", absolute(reset_time_str)),
+            (ResetFormat::Absolute, true) => format!("→ {} (stale)", absolute(reset_time_str)),
+            (ResetFormat::Countdown, false) => Self::format_reset_countdown(reset_time_str),
+            (ResetFormat::Countdown, true) => "reset (stale)".to_string(),
         }
-        "?".to_string()
     }
 
     pub fn get_cache_path() -> Option<std::path::PathBuf> {
@@ -334,7 +331,11 @@ impl Segment for UsageSegment {
         let dynamic_icon = Self::get_circle_icon(seven_day_util / 100.0);
         let five_hour_percent = five_hour_util.round() as u8;
         let primary = format!("{}%", five_hour_percent);
-        let secondary = format!("· {}", Self::format_reset_time(resets_at.as_deref()));
+        let reset =
+            Self::format_reset_display(resets_at.as_deref(), self.reset_format, |reset_time| {
+                Self::format_7day_reset_time(reset_time, &self.formatter)
+            });
+        let secondary = format!("· {}", reset);
 
         let mut metadata = HashMap::new();
         metadata.insert("dynamic_icon".to_string(), dynamic_icon);
@@ -1,46 +1,288 @@
-use super::{color_utils, threshold_utils, Segment, SegmentData};
+use super::threshold_utils::Thresholds;
+use super::{color_utils, Segment, SegmentData};
 use crate::config::{InputData, SegmentId};
-use crate::core::segments::usage::UsageSegment;
+use crate::core::blocks::{self, BillingBlock};
+use crate::core::segments::cost::CostSegment;
+use crate::core::segments::usage::{ResetFormat, UsageSegment};
+use crate::core::spend;
+use crate::utils::TimeFormatter;
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-#[derive(Default)]
-pub struct Usage5HourSegment;
+/// Where the 5-hour segment takes its usage from
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum UsageSource {
+    /// The OAuth usage API cache, falling back to local transcripts when missing
+    #[default]
+    Auto,
+    /// Only the OAuth usage API
+    Api,
+    /// Billing blocks estimated from local transcripts
+    Local,
+}
+
+impl UsageSource {
+    pub fn from_option(value: Option<&str>) -> Self {
+        match value {
+            Some("api") => Self::Api,
+            Some("local") => Self::Local,
+            _ => Self::Auto,
+        }
+    }
+}
+
+/// Cached local block so the statusline doesn't rescan every transcript on each refresh
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BlockCache {
+    /// None when no block was active
+    pub block: Option<BillingBlock>,
+    pub cached_at: String,
+}
+
+pub struct Usage5HourSegment {
+    formatter: TimeFormatter,
+    reset_format: ResetFormat,
+    source: UsageSource,
+    token_limit: Option<u64>,
+    show_projection: bool,
+    local_cache_duration: u64,
+    thresholds: Thresholds,
+}
+
+impl Default for Usage5HourSegment {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
 impl Usage5HourSegment {
     pub fn new() -> Self {
-        Self
+        Self {
+            formatter: TimeFormatter::default(),
+            reset_format: ResetFormat::default(),
+            source: UsageSource::default(),
+            token_limit: None,
+            show_projection: false,
+            local_cache_duration: 60,
+            thresholds: Thresholds::default(),
+        }
+    }
+
+    /// Warning and critical settings from the segment's options
+    pub fn with_thresholds(mut self, thresholds: Thresholds) -> Self {
+        self.thresholds = thresholds;
+        self
+    }
+
+    pub fn with_formatter(mut self, formatter: TimeFormatter) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    pub fn with_reset_format(mut self, reset_format: ResetFormat) -> Self {
+        self.reset_format = reset_format;
+        self
+    }
+
+    pub fn with_source(mut self, source: UsageSource) -> Self {
+        self.source = source;
+        self
+    }
+
+    /// Token budget per block for local estimates; without one the block cost is shown
+    pub fn with_token_limit(mut self, token_limit: Option<u64>) -> Self {
+        self.token_limit = token_limit.filter(|limit| *limit > 0);
+        self
+    }
+
+    pub fn with_projection(mut self, show_projection: bool) -> Self {
+        self.show_projection = show_projection;
+        self
+    }
+
+    /// Seconds to reuse the block estimated from local transcripts
+    pub fn with_local_cache_duration(mut self, local_cache_duration: u64) -> Self {
+        self.local_cache_duration = local_cache_duration;
+        self
+    }
+
+    pub fn get_block_cache_path() -> Option<std::path::PathBuf> {
+        let home = dirs::home_dir()?;
+        Some(
+            home.join(".claude")
+                .join("ccline")
+                .join(".block_cache.json"),
+        )
+    }
+
+    fn load_block_cache(&self) -> Option<BlockCache> {
+        let content = std::fs::read_to_string(Self::get_block_cache_path()?).ok()?;
+        let cache: BlockCache = serde_json::from_str(&content).ok()?;
+
+        let cached_at = DateTime::parse_from_rfc3339(&cache.cached_at).ok()?;
+        let elapsed = Utc::now().signed_duration_since(cached_at.with_timezone(&Utc));
+        (elapsed.num_seconds() < self.local_cache_duration as i64).then_some(cache)
+    }
+
+    fn save_block_cache(&self, cache: &BlockCache) {
+        if let Some(cache_path) = Self::get_block_cache_path() {
+            if let Some(parent) = cache_path.parent() {
+                let _ = std::fs::create_dir_all(parent);
+            }
+            // Write a temp file per process and rename it, so concurrent renders never
+            // read a partly written cache
+            if let Ok(json) = serde_json::to_string_pretty(cache) {
+                let temp_path =
+                    cache_path.with_extension(format!("json.{}.tmp", std::process::id()));
+                if std::fs::write(&temp_path, json).is_ok()
+                    && std::fs::rename(&temp_path, &cache_path).is_err()
+                {
+                    let _ = std::fs::remove_file(&temp_path);
+                }
+            }
+        }
+    }
+
+    /// The active block, from the cache when it is fresh enough
+    fn current_block(&self, now: DateTime<Utc>) -> Option<BillingBlock> {
+        if let Some(cache) = self.load_block_cache() {
+            return cache.block.filter(|block| block.is_active(now));
+        }
+
+        let block = blocks::current_block(now);
+        self.save_block_cache(&BlockCache {
+            block: block.clone(),
+            cached_at: now.to_rfc3339(),
+        });
+        block
+    }
+
+    /// Display the current billing block reconstructed from local transcripts
+    fn collect_local(&self) -> Option<SegmentData> {
+        let now = Utc::now();
+        let block = self.current_block(now)?;
+
+        let ends_at = block.end.to_rfc3339_opts(SecondsFormat::Secs, true);
+        let (projected_tokens, projected_cost) = block.projected(now);
+        let has_cost = block.messages > block.unpriced_messages;
+
+        let utilization = self
+            .token_limit
+            .map(|limit| block.tokens as f64 / limit as f64 * 100.0);
+
+        // With a token limit show usage like the API does, otherwise the block's spend
+        let primary = match utilization {
+            Some(percent) => format!("~{}%", percent.round() as u64),
+            None if has_cost => format!("~{}", CostSegment::format_cost(block.cost_usd)),
+            None => spend::format_tokens(block.tokens),
+        };
+
+        let mut secondary =
+            UsageSegment::format_reset_display(Some(&ends_at), self.reset_format, |reset_time| {
+                UsageSegment::format_5hour_reset_time(reset_time, &self.formatter)
+            });
+        if self.show_projection {
+            let projection = match utilization {
+                Some(_) => spend::format_tokens(projected_tokens),
+                None if has_cost => CostSegment::format_cost(projected_cost),
+                None => spend::format_tokens(projected_tokens),
+            };
+            secondary.push_str(&format!(" · proj ~{}", projection));
+        }
+
+        // Without a limit the icon follows how much of the block has elapsed
+        let icon_percent = utilization.unwrap_or_else(|| block.elapsed_percent(now));
+        let dynamic_icon = UsageSegment::get_circle_icon(icon_percent / 100.0);
+
+        let mut metadata = HashMap::new();
+        metadata.insert("dynamic_icon".to_string(), dynamic_icon);
+        metadata.insert("usage_source".to_string(), "local".to_string());
+        metadata.insert("block_start".to_string(), block.start.to_rfc3339());
+        metadata.insert("resets_at".to_string(), ends_at);
+        metadata.insert(
+            "remaining_minutes".to_string(),
+            block.remaining(now).num_minutes().to_string(),
+        );
+        metadata.insert("block_messages".to_string(), block.messages.to_string());
+        metadata.insert("block_tokens".to_string(), block.tokens.to_string());
+        metadata.insert("block_cost".to_string(), block.cost_usd.to_string());
+        metadata.insert("projected_tokens".to_string(), projected_tokens.to_string());
+        metadata.insert("projected_cost".to_string(), projected_cost.to_string());
+        metadata.insert(
+            "unpriced_messages".to_string(),
+            block.unpriced_messages.to_string(),
+        );
+
+        if let Some(percent) = utilization {
+            metadata.insert("five_hour_utilization".to_string(), percent.to_string());
+
+            if let Some(color) = self.thresholds.color_for_utilization(percent) {
+                let color_json = color_utils::serialize_ansi_color_to_json(&color);
+                metadata.insert("text_color_override".to_string(), color_json);
+            }
+
+            if let Some(should_bold) = self.thresholds.bold_for_utilization(percent) {
+                metadata.insert("text_bold_override".to_string(), should_bold.to_string());
+            }
+        }
+
+        Some(SegmentData {
+            primary,
+            secondary,
+            metadata,
+        })
     }
 }
 
 impl Segment for Usage5HourSegment {
     fn collect(&self, _input: &InputData) -> Option<SegmentData> {
+        if self.source == UsageSource::Local {
+            return self.collect_local();
+        }
+
         // Load the shared cache created by UsageSegment
-        let cache = UsageSegment::load_usage_cache()?;
+        let cache = match UsageSegment::load_usage_cache() {
+            Some(cache) => cache,
+            None if self.source == UsageSource::Auto => return self.collect_local(),
+            None => return None,
+        };
 
         // Note: five_hour_utilization is a percentage (0-100) from the API
         let five_hour_util = cache.five_hour_utilization;
-        let reset_time = UsageSegment::format_5hour_reset_time(cache.five_hour_resets_at.as_deref());
+        let resets_at = cache.five_hour_resets_at.as_deref();
+        let secondary =
+            UsageSegment::format_reset_display(resets_at, self.reset_format, |reset_time| {
+                UsageSegment::format_5hour_reset_time(reset_time, &self.formatter)
+            });
 
         // Convert percentage (0-100) to normalized value (0-1) for get_circle_icon
         let dynamic_icon = UsageSegment::get_circle_icon(five_hour_util / 100.0);
 
         let five_hour_percent = five_hour_util.round() as u8;
         let primary = format!("{}%", five_hour_percent);
-        let secondary = format!("→ {}", reset_time);
 
         let mut metadata = HashMap::new();
         metadata.insert("dynamic_icon".to_string(), dynamic_icon);
+        metadata.insert("usage_source".to_string(), "api".to_string());
         metadata.insert("five_hour_utilization".to_string(), five_hour_util.to_string());
+        if let Some(resets_at) = resets_at {
+            metadata.insert("resets_at".to_string(), resets_at.to_string());
+        }
+        metadata.insert(
+            "reset_stale".to_string(),
+            UsageSegment::is_reset_passed(resets_at).to_string(),
+        );
 
         // Check if we need to apply threshold-based color override
-        if let Some(color) = threshold_utils::get_color_for_utilization(SegmentId::Usage5Hour, five_hour_util) {
+        if let Some(color) = self.thresholds.color_for_utilization(five_hour_util) {
             // Serialize the color to JSON for metadata using shared helper
             let color_json = color_utils::serialize_ansi_color_to_json(&color);
             metadata.insert("text_color_override".to_string(), color_json);
         }
 
         // Check if we need to apply threshold-based bold override
-        if let Some(should_bold) = threshold_utils::should_be_bold(SegmentId::Usage5Hour, five_hour_util) {
+        if let Some(should_bold) = self.thresholds.bold_for_utilization(five_hour_util) {
             metadata.insert("text_bold_override".to_string(), should_bold.to_string());
         }
 
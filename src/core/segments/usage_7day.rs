@@ -1,14 +1,36 @@
-use super::{color_utils, threshold_utils, Segment, SegmentData};
+use super::threshold_utils::Thresholds;
+use super::{color_utils, Segment, SegmentData};
 use crate::config::{InputData, SegmentId};
-use crate::core::segments::usage::UsageSegment;
+use crate::core::segments::usage::{ResetFormat, UsageSegment};
+use crate::utils::TimeFormatter;
 use std::collections::HashMap;
 
 #[derive(Default)]
-pub struct Usage7DaySegment;
+pub struct Usage7DaySegment {
+    formatter: TimeFormatter,
+    reset_format: ResetFormat,
+    thresholds: Thresholds,
+}
 
 impl Usage7DaySegment {
     pub fn new() -> Self {
-        Self
+        Self::default()
+    }
+
+    /// Warning and critical settings from the segment's options
+    pub fn with_thresholds(mut self, thresholds: Thresholds) -> Self {
+        self.thresholds = thresholds;
+        self
+    }
+
+    pub fn with_formatter(mut self, formatter: TimeFormatter) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    pub fn with_reset_format(mut self, reset_format: ResetFormat) -> Self {
+        self.reset_format = reset_format;
+        self
     }
 }
 
@@ -19,28 +41,38 @@ impl Segment for Usage7DaySegment {
 
         // Note: seven_day_utilization is a percentage (0-100) from the API
         let seven_day_util = cache.seven_day_utilization;
-        let reset_time = UsageSegment::format_7day_reset_time(cache.seven_day_resets_at.as_deref());
+        let resets_at = cache.seven_day_resets_at.as_deref();
+        let secondary =
+            UsageSegment::format_reset_display(resets_at, self.reset_format, |reset_time| {
+                UsageSegment::format_7day_reset_time(reset_time, &self.formatter)
+            });
 
         // Convert percentage (0-100) to normalized value (0-1) for get_circle_icon
         let dynamic_icon = UsageSegment::get_circle_icon(seven_day_util / 100.0);
 
         let seven_day_percent = seven_day_util.round() as u8;
         let primary = format!("{}%", seven_day_percent);
-        let secondary = format!("→ {}", reset_time);
 
         let mut metadata = HashMap::new();
         metadata.insert("dynamic_icon".to_string(), dynamic_icon);
         metadata.insert("seven_day_utilization".to_string(), seven_day_util.to_string());
+        if let Some(resets_at) = resets_at {
+            metadata.insert("resets_at".to_string(), resets_at.to_string());
+        }
+        metadata.insert(
+            "reset_stale".to_string(),
+            UsageSegment::is_reset_passed(resets_at).to_string(),
+        );
 
         // Check if we need to apply threshold-based color override
-        if let Some(color) = threshold_utils::get_color_for_utilization(SegmentId::Usage7Day, seven_day_util) {
+        if let Some(color) = self.thresholds.color_for_utilization(seven_day_util) {
             // Serialize the color to JSON for metadata using shared helper
             let color_json = color_utils::serialize_ansi_color_to_json(&color);
             metadata.insert("text_color_override".to_string(), color_json);
         }
 
         // Check if we need to apply threshold-based bold override
-        if let Some(should_bold) = threshold_utils::should_be_bold(SegmentId::Usage7Day, seven_day_util) {
+        if let Some(should_bold) = self.thresholds.bold_for_utilization(seven_day_util) {
             metadata.insert("text_bold_override".to_string(), should_bold.to_string());
         }
 
@@ -0,0 +1,257 @@
+use crate::config::{ModelConfig, NormalizedUsage};
+use crate::core::transcript::{self, MessageDeduper};
+use chrono::{DateTime, Datelike, Local, NaiveDate, NaiveTime, TimeZone, Utc};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// A single deduplicated assistant usage record from a transcript
+#[derive(Debug, Clone)]
+pub struct UsageRecord {
+    pub timestamp: DateTime<Utc>,
+    pub project: String,
+    pub model: String,
+    pub usage: NormalizedUsage,
+    /// None when the model has no pricing in models.toml
+    pub cost_usd: Option<f64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GroupBy {
+    Day,
+    Week,
+    Month,
+    Project,
+    Model,
+}
+
+impl GroupBy {
+    fn key(&self, record: &UsageRecord) -> String {
+        let local = record.timestamp.with_timezone(&Local);
+        match self {
+            GroupBy::Day => local.format("%Y-%m-%d").to_string(),
+            GroupBy::Week => local.format("%G-W%V").to_string(),
+            GroupBy::Month => local.format("%Y-%m").to_string(),
+            GroupBy::Project => record.project.clone(),
+            GroupBy::Model => record.model.clone(),
+        }
+    }
+}
+
+/// Aggregated tokens and cost for one group
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SpendRow {
+    pub key: String,
+    pub messages: u64,
+    pub input_tokens: u64,
+    pub output_tokens: u64,
+    pub cache_creation_tokens: u64,
+    pub cache_read_tokens: u64,
+    pub total_tokens: u64,
+    pub cost_usd: f64,
+    pub unpriced_messages: u64,
+}
+
+impl SpendRow {
+    fn add(&mut self, record: &UsageRecord) {
+        let usage = &record.usage;
+        self.messages += 1;
+        self.input_tokens += usage.input_tokens as u64;
+        self.output_tokens += usage.output_tokens as u64;
+        self.cache_creation_tokens += usage.cache_creation_input_tokens as u64;
+        self.cache_read_tokens += usage.cache_read_input_tokens as u64;
+        self.total_tokens += usage.total_for_cost() as u64;
+        match record.cost_usd {
+            Some(cost) => self.cost_usd += cost,
+            None => self.unpriced_messages += 1,
+        }
+    }
+}
+
+/// Get the Claude projects directory, respecting CLAUDE_CONFIG_DIR
+pub fn claude_projects_dir() -> Option<PathBuf> {
+    if let Ok(config_dir) = std::env::var("CLAUDE_CONFIG_DIR") {
+        let path = PathBuf::from(config_dir).join("projects");
+        if path.exists() {
+            return Some(path);
+        }
+    }
+
+    let home = dirs::home_dir()?;
+    Some(home.join(".claude").join("projects"))
+}
+
+/// List every `projects/*/*.jsonl` transcript modified at or after `modified_since`
+pub fn find_transcripts(projects_dir: &Path, modified_since: Option<SystemTime>) -> Vec<PathBuf> {
+    let mut transcripts = Vec::new();
+
+    let project_dirs = match fs::read_dir(projects_dir) {
+        Ok(entries) => entries,
+        Err(_) => return transcripts,
+    };
+
+    for project_dir in project_dirs.flatten() {
+        let files = match fs::read_dir(project_dir.path()) {
+            Ok(files) => files,
+            Err(_) => continue,
+        };
+
+        for file in files.flatten() {
+            let path = file.path();
+            if path.extension().and_then(|s| s.to_str()) != Some("jsonl") {
+                continue;
+            }
+
+            // Files untouched since the cutoff can't contain newer messages
+            if let Some(cutoff) = modified_since {
+                let modified = file
+                    .metadata()
+                    .and_then(|m| m.modified())
+                    .unwrap_or(SystemTime::UNIX_EPOCH);
+                if modified < cutoff {
+                    continue;
+                }
+            }
+
+            transcripts.push(path);
+        }
+    }
+
+    // Oldest first, so a resumed session's copied history is attributed to its original file
+    transcripts.sort_by_key(|path| {
+        fs::metadata(path)
+            .and_then(|m| m.modified())
+            .unwrap_or(SystemTime::UNIX_EPOCH)
+    });
+
+    transcripts
+}
+
+/// Collect deduplicated usage records from all transcripts under `projects_dir`
+/// Only messages at or after `since` are kept when it is set
+pub fn collect_usage_records(
+    projects_dir: &Path,
+    since: Option<DateTime<Utc>>,
+    model_config: &ModelConfig,
+) -> Vec<UsageRecord> {
+    let modified_since = since.map(SystemTime::from);
+    // Resumed sessions copy history into new files, so dedupe across all files
+    let mut deduper = MessageDeduper::new();
+    let mut records = Vec::new();
+
+    for path in find_transcripts(projects_dir, modified_since) {
+        let project = path
+            .parent()
+            .and_then(|p| p.file_name())
+            .and_then(|n| n.to_str())
+            .unwrap_or("unknown")
+            .to_string();
+
+        let entries = match transcript::entries(&path) {
+            Ok(entries) => entries,
+            Err(_) => continue,
+        };
+
+        for entry in entries {
+            if !entry.is_assistant() {
+                continue;
+            }
+
+            let timestamp = match entry.time() {
+                Some(timestamp) => timestamp,
+                None => continue,
+            };
+
+            if since.is_some_and(|since| timestamp < since) || !deduper.first_seen(&entry) {
+                continue;
+            }
+
+            let usage = match entry.usage() {
+                Some(usage) => usage,
+                None => continue,
+            };
+
+            let model = entry.model().unwrap_or("unknown").to_string();
+            let cost_usd = model_config
+                .get_pricing(&model)
+                .map(|pricing| pricing.cost(&usage));
+
+            records.push(UsageRecord {
+                timestamp,
+                project: project.clone(),
+                model,
+                usage,
+                cost_usd,
+            });
+        }
+    }
+
+    records
+}
+
+/// Group records and sum tokens and cost, sorted by key
+pub fn aggregate(records: &[UsageRecord], group_by: GroupBy) -> Vec<SpendRow> {
+    let mut groups: BTreeMap<String, SpendRow> = BTreeMap::new();
+
+    for record in records {
+        let key = group_by.key(record);
+        groups
+            .entry(key.clone())
+            .or_insert_with(|| SpendRow {
+                key,
+                ..Default::default()
+            })
+            .add(record);
+    }
+
+    groups.into_values().collect()
+}
+
+/// Sum the cost of records at or after `since`
+pub fn total_cost_since(records: &[UsageRecord], since: DateTime<Utc>) -> f64 {
+    records
+        .iter()
+        .filter(|record| record.timestamp >= since)
+        .filter_map(|record| record.cost_usd)
+        .sum()
+}
+
+/// Compact token count, e.g. "1.2M", "45.3k" or "812"
+pub fn format_tokens(tokens: u64) -> String {
+    if tokens >= 1_000_000 {
+        format!("{:.1}M", tokens as f64 / 1_000_000.0)
+    } else if tokens >= 1000 {
+        format!("{:.1}k", tokens as f64 / 1000.0)
+    } else {
+        tokens.to_string()
+    }
+}
+
+/// Local midnight today, in UTC
+pub fn start_of_day() -> DateTime<Utc> {
+    let today = Local::now().date_naive();
+    local_midnight(today)
+}
+
+/// Local midnight of this week's Monday, in UTC
+pub fn start_of_week() -> DateTime<Utc> {
+    let today = Local::now().date_naive();
+    let days_from_monday = today.weekday().num_days_from_monday() as u64;
+    local_midnight(today - chrono::Days::new(days_from_monday))
+}
+
+/// Local midnight of the first day of this month, in UTC
+pub fn start_of_month() -> DateTime<Utc> {
+    let today = Local::now().date_naive();
+    local_midnight(today.with_day(1).unwrap_or(today))
+}
+
+fn local_midnight(date: NaiveDate) -> DateTime<Utc> {
+    let midnight = date.and_time(NaiveTime::MIN);
+    match Local.from_local_datetime(&midnight).earliest() {
+        Some(dt) => dt.with_timezone(&Utc),
+        None => midnight.and_utc(),
+    }
+}
@@ -65,6 +65,55 @@ impl StatusLineGenerator {
         }
     }
 
+    /// Statusline for input that failed to parse: an error glyph in front of
+    /// whatever segments could still be rendered from the salvaged fields
+    pub fn generate_degraded(&self, segments: Vec<(SegmentConfig, SegmentData)>) -> String {
+        let glyph = match self.config.style.mode {
+            StyleMode::Plain => "⚠",
+            StyleMode::NerdFont | StyleMode::Powerline => "\u{f071}",
+        };
+        let marker = self.apply_style(glyph, Some(&AnsiColor::Color16 { c16: 9 }), true);
+
+        let statusline = self.generate(segments);
+        if statusline.is_empty() {
+            marker
+        } else {
+            format!("{} {}", marker, statusline)
+        }
+    }
+
+    /// Segment data as JSON, one object per enabled segment that produced output
+    /// Metadata values holding JSON arrays or objects (e.g. file lists) are embedded as JSON
+    pub fn generate_json(&self, segments: Vec<(SegmentConfig, SegmentData)>) -> serde_json::Value {
+        let segments: Vec<serde_json::Value> = segments
+            .into_iter()
+            .filter(|(config, _)| config.enabled)
+            .map(|(config, data)| {
+                let metadata: serde_json::Map<String, serde_json::Value> = data
+                    .metadata
+                    .into_iter()
+                    .map(|(key, value)| {
+                        let embedded = if value.starts_with('[') || value.starts_with('{') {
+                            serde_json::from_str(&value).ok()
+                        } else {
+                            None
+                        };
+                        (key, embedded.unwrap_or(serde_json::Value::String(value)))
+                    })
+                    .collect();
+
+                serde_json::json!({
+                    "id": config.id,
+                    "primary": data.primary,
+                    "secondary": data.secondary,
+                    "metadata": metadata,
+                })
+            })
+            .collect();
+
+        serde_json::json!({ "segments": segments })
+    }
+
     /// Generate statusline for TUI preview with proper width calculation
     /// This method handles ANSI escape sequences properly for ratatui rendering
     #[cfg(feature = "tui")]
@@ -489,18 +538,55 @@ pub fn collect_all_segments(
     config: &Config,
     input: &crate::config::InputData,
 ) -> Vec<(SegmentConfig, SegmentData)> {
+    use crate::core::segments::context_window::ContextBase;
+    use crate::core::segments::cost::CostSource;
+    use crate::core::segments::progress_bar::ProgressBar;
+    use crate::core::segments::threshold_utils::Thresholds;
+    use crate::core::segments::usage::ResetFormat;
+    use crate::core::segments::usage_5hour::UsageSource;
     use crate::core::segments::*;
+    use crate::utils::TimeFormatter;
 
+    let formatter = TimeFormatter::new(&config.time);
     let mut results = Vec::new();
 
     for segment_config in &config.segments {
+        // Disabled segments are never rendered, so don't pay for their transcript scans
+        if !segment_config.enabled {
+            continue;
+        }
+
         let segment_data = match segment_config.id {
             crate::config::SegmentId::Model => {
-                let segment = ModelSegment::new();
+                let option_bool = |key: &str, default: bool| {
+                    segment_config
+                        .options
+                        .get(key)
+                        .and_then(|v| v.as_bool())
+                        .unwrap_or(default)
+                };
+                let segment = ModelSegment::new()
+                    .with_served(option_bool("show_served", true))
+                    .with_mismatch_warning(option_bool("warn_on_mismatch", true))
+                    .with_turn_counts(option_bool("show_turn_counts", false))
+                    .with_thresholds(Thresholds::from_config(segment_config));
                 segment.collect(input)
             }
             crate::config::SegmentId::Directory => {
-                let segment = DirectorySegment::new();
+                let show_project = segment_config
+                    .options
+                    .get("show_project")
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or(true);
+                let warn_outside_project = segment_config
+                    .options
+                    .get("warn_outside_project")
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or(true);
+                let segment = DirectorySegment::new()
+                    .with_project(show_project)
+                    .with_outside_warning(warn_outside_project)
+                    .with_thresholds(Thresholds::from_config(segment_config));
                 segment.collect(input)
             }
             crate::config::SegmentId::Git => {
@@ -520,23 +606,112 @@ pub fn collect_all_segments(
                 segment.collect(input)
             }
             crate::config::SegmentId::ContextWindow => {
-                let segment = ContextWindowSegment::new();
+                let include_output = segment_config
+                    .options
+                    .get("include_output")
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or(true);
+                let base = ContextBase::from_option(
+                    segment_config
+                        .options
+                        .get("relative_to")
+                        .and_then(|v| v.as_str()),
+                );
+                let show_forecast = segment_config
+                    .options
+                    .get("show_forecast")
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or(false);
+                let segment = ContextWindowSegment::new()
+                    .with_output(include_output)
+                    .with_base(base)
+                    .with_forecast(show_forecast)
+                    .with_thresholds(Thresholds::from_config(segment_config));
                 segment.collect(input)
             }
             crate::config::SegmentId::Usage => {
-                let segment = UsageSegment::new();
+                let reset_format = ResetFormat::from_option(
+                    segment_config
+                        .options
+                        .get("reset_format")
+                        .and_then(|v| v.as_str()),
+                );
+                let segment = UsageSegment::new()
+                    .with_formatter(formatter)
+                    .with_reset_format(reset_format);
                 segment.collect(input)
             }
             crate::config::SegmentId::Usage5Hour => {
-                let segment = Usage5HourSegment::new();
+                let reset_format = ResetFormat::from_option(
+                    segment_config
+                        .options
+                        .get("reset_format")
+                        .and_then(|v| v.as_str()),
+                );
+                let source = UsageSource::from_option(
+                    segment_config
+                        .options
+                        .get("source")
+                        .and_then(|v| v.as_str()),
+                );
+                let token_limit = segment_config
+                    .options
+                    .get("token_limit")
+                    .and_then(|v| v.as_u64());
+                let show_projection = segment_config
+                    .options
+                    .get("show_projection")
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or(false);
+                let local_cache_duration = segment_config
+                    .options
+                    .get("local_cache_duration")
+                    .and_then(|v| v.as_u64())
+                    .unwrap_or(60);
+                let segment = Usage5HourSegment::new()
+                    .with_formatter(formatter)
+                    .with_reset_format(reset_format)
+                    .with_source(source)
+                    .with_token_limit(token_limit)
+                    .with_projection(show_projection)
+                    .with_local_cache_duration(local_cache_duration)
+                    .with_thresholds(Thresholds::from_config(segment_config));
                 segment.collect(input)
             }
             crate::config::SegmentId::Usage7Day => {
-                let segment = Usage7DaySegment::new();
+                let reset_format = ResetFormat::from_option(
+                    segment_config
+                        .options
+                        .get("reset_format")
+                        .and_then(|v| v.as_str()),
+                );
+                let segment = Usage7DaySegment::new()
+                    .with_formatter(formatter)
+                    .with_reset_format(reset_format)
+                    .with_thresholds(Thresholds::from_config(segment_config));
                 segment.collect(input)
             }
             crate::config::SegmentId::Cost => {
-                let segment = CostSegment::new();
+                let source = CostSource::from_option(
+                    segment_config
+                        .options
+                        .get("source")
+                        .and_then(|v| v.as_str()),
+                );
+                let budget_usd = segment_config
+                    .options
+                    .get("budget_usd")
+                    .and_then(|v| v.as_f64());
+                let show_remaining = segment_config
+                    .options
+                    .get("show_remaining")
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or(false);
+                let segment = CostSegment::new()
+                    .with_source(source)
+                    .with_budget(budget_usd)
+                    .with_remaining(show_remaining)
+                    .with_thresholds(Thresholds::from_config(segment_config));
                 segment.collect(input)
             }
             crate::config::SegmentId::Session => {
@@ -551,9 +726,215 @@ pub fn collect_all_segments(
                 let segment = UpdateSegment::new();
                 segment.collect(input)
             }
+            crate::config::SegmentId::Time => {
+                let format = segment_config
+                    .options
+                    .get("format")
+                    .and_then(|v| v.as_str())
+                    .map(|s| s.to_string());
+                let timezone = segment_config
+                    .options
+                    .get("timezone")
+                    .and_then(|v| v.as_str())
+                    .map(|s| s.to_string());
+                let segment = TimeSegment::new()
+                    .with_formatter(formatter)
+                    .with_format(format)
+                    .with_timezone(timezone);
+                segment.collect(input)
+            }
+            crate::config::SegmentId::BurnRate => {
+                let window_minutes = segment_config
+                    .options
+                    .get("window_minutes")
+                    .and_then(|v| v.as_u64())
+                    .unwrap_or(10);
+                let include_cache_reads = segment_config
+                    .options
+                    .get("include_cache_reads")
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or(false);
+                let segment = BurnRateSegment::new()
+                    .with_window_minutes(window_minutes)
+                    .with_cache_reads(include_cache_reads)
+                    .with_thresholds(Thresholds::from_config(segment_config));
+                segment.collect(input)
+            }
+            crate::config::SegmentId::Spend => {
+                let show_week = segment_config
+                    .options
+                    .get("show_week")
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or(true);
+                let show_month = segment_config
+                    .options
+                    .get("show_month")
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or(false);
+                let cache_duration = segment_config
+                    .options
+                    .get("cache_duration")
+                    .and_then(|v| v.as_u64())
+                    .unwrap_or(60);
+                let budget_usd = segment_config
+                    .options
+                    .get("budget_usd")
+                    .and_then(|v| v.as_f64());
+                let show_remaining = segment_config
+                    .options
+                    .get("show_remaining")
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or(false);
+                let segment = SpendSegment::new()
+                    .with_week(show_week)
+                    .with_month(show_month)
+                    .with_cache_duration(cache_duration)
+                    .with_budget(budget_usd)
+                    .with_remaining(show_remaining)
+                    .with_thresholds(Thresholds::from_config(segment_config));
+                segment.collect(input)
+            }
+            crate::config::SegmentId::CacheHit => {
+                let show_session = segment_config
+                    .options
+                    .get("show_session")
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or(true);
+                let show_savings = segment_config
+                    .options
+                    .get("show_savings")
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or(true);
+                let segment = CacheHitSegment::new()
+                    .with_session(show_session)
+                    .with_savings(show_savings)
+                    .with_thresholds(Thresholds::from_config(segment_config));
+                segment.collect(input)
+            }
+            crate::config::SegmentId::Compaction => {
+                let warning_minutes = segment_config
+                    .options
+                    .get("warning_interval_minutes")
+                    .and_then(|v| v.as_u64())
+                    .unwrap_or(60);
+                let critical_minutes = segment_config
+                    .options
+                    .get("critical_interval_minutes")
+                    .and_then(|v| v.as_u64())
+                    .unwrap_or(20);
+                let segment = CompactionSegment::new()
+                    .with_intervals(warning_minutes, critical_minutes)
+                    .with_thresholds(Thresholds::from_config(segment_config));
+                segment.collect(input)
+            }
+            crate::config::SegmentId::ToolActivity => {
+                let option_bool = |key: &str| {
+                    segment_config
+                        .options
+                        .get(key)
+                        .and_then(|v| v.as_bool())
+                        .unwrap_or(true)
+                };
+                let top_tools = segment_config
+                    .options
+                    .get("top_tools")
+                    .and_then(|v| v.as_u64())
+                    .unwrap_or(3) as usize;
+                let max_target_length = segment_config
+                    .options
+                    .get("max_target_length")
+                    .and_then(|v| v.as_u64())
+                    .unwrap_or(30) as usize;
+                let segment = ToolActivitySegment::new()
+                    .with_last(option_bool("show_last"))
+                    .with_total(option_bool("show_total"))
+                    .with_by_tool(option_bool("show_by_tool"), top_tools)
+                    .with_errors(option_bool("show_errors"))
+                    .with_max_target_length(max_target_length);
+                segment.collect(input)
+            }
+            crate::config::SegmentId::Todos => {
+                let show_current = segment_config
+                    .options
+                    .get("show_current")
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or(true);
+                let max_text_length = segment_config
+                    .options
+                    .get("max_text_length")
+                    .and_then(|v| v.as_u64())
+                    .unwrap_or(40) as usize;
+                let segment = TodosSegment::new()
+                    .with_current(show_current)
+                    .with_max_text_length(max_text_length);
+                segment.collect(input)
+            }
+            crate::config::SegmentId::Subagents => {
+                let option_bool = |key: &str, default: bool| {
+                    segment_config
+                        .options
+                        .get(key)
+                        .and_then(|v| v.as_bool())
+                        .unwrap_or(default)
+                };
+                let max_text_length = segment_config
+                    .options
+                    .get("max_text_length")
+                    .and_then(|v| v.as_u64())
+                    .unwrap_or(30) as usize;
+                let segment = SubagentsSegment::new()
+                    .with_descriptions(option_bool("show_descriptions", true))
+                    .with_tokens(option_bool("show_tokens", true))
+                    .with_hide_when_idle(option_bool("hide_when_idle", false))
+                    .with_max_text_length(max_text_length);
+                segment.collect(input)
+            }
+            crate::config::SegmentId::FilesTouched => {
+                let show_last = segment_config
+                    .options
+                    .get("show_last")
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or(true);
+                let segment = FilesTouchedSegment::new().with_last(show_last);
+                segment.collect(input)
+            }
+            crate::config::SegmentId::TurnStats => {
+                let format = segment_config
+                    .options
+                    .get("format")
+                    .and_then(|v| v.as_str())
+                    .map(|s| s.to_string());
+                let segment = TurnStatsSegment::new().with_format(format);
+                segment.collect(input)
+            }
+            crate::config::SegmentId::Thinking => {
+                let hide_when_off = segment_config
+                    .options
+                    .get("hide_when_off")
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or(false);
+                let segment = ThinkingSegment::new().with_hide_when_off(hide_when_off);
+                segment.collect(input)
+            }
+            crate::config::SegmentId::ClaudeVersion => {
+                let segment = ClaudeVersionSegment::new();
+                segment.collect(input)
+            }
+            crate::config::SegmentId::SessionId => {
+                let length = segment_config
+                    .options
+                    .get("length")
+                    .and_then(|v| v.as_u64())
+                    .unwrap_or(8) as usize;
+                let segment = SessionIdSegment::new().with_length(length);
+                segment.collect(input)
+            }
         };
 
-        if let Some(data) = segment_data {
+        if let Some(mut data) = segment_data {
+            if let Some(bar) = ProgressBar::from_options(&segment_config.options) {
+                bar.apply(&mut data);
+            }
             results.push((segment_config.clone(), data));
         }
     }
@@ -0,0 +1,236 @@
+use crate::config::{NormalizedUsage, RawUsage};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+/// Kind of a transcript line, from its `type` field
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntryKind {
+    User,
+    Assistant,
+    Summary,
+    System,
+    Other,
+}
+
+/// One line of a Claude Code JSONL transcript
+/// Every field is optional so unknown or partial lines still parse
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Entry {
+    #[serde(rename = "type")]
+    pub r#type: Option<String>,
+    pub uuid: Option<String>,
+    pub parent_uuid: Option<String>,
+    /// Set on summary entries, pointing at the last message of the summarized conversation
+    pub leaf_uuid: Option<String>,
+    pub summary: Option<String>,
+    /// Refines system entries, e.g. "compact_boundary"
+    pub subtype: Option<String>,
+    /// Set on compact boundaries
+    pub compact_metadata: Option<CompactMetadata>,
+    pub timestamp: Option<String>,
+    pub session_id: Option<String>,
+    pub request_id: Option<String>,
+    pub cwd: Option<String>,
+    pub git_branch: Option<String>,
+    /// Claude Code version that wrote the entry
+    pub version: Option<String>,
+    /// True for entries written by subagents (Task tool)
+    #[serde(default)]
+    pub is_sidechain: bool,
+    /// True for user entries Claude Code injects itself (command output, caveats)
+    #[serde(default)]
+    pub is_meta: bool,
+    pub message: Option<Message>,
+}
+
+/// What Claude Code recorded about a compaction
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompactMetadata {
+    /// "auto" or "manual"
+    pub trigger: Option<String>,
+    /// Context tokens just before the conversation was compacted
+    pub pre_tokens: Option<u64>,
+}
+
+/// The API message carried by user and assistant entries
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct Message {
+    pub id: Option<String>,
+    pub role: Option<String>,
+    pub model: Option<String>,
+    #[serde(default)]
+    pub content: MessageContent,
+    pub usage: Option<RawUsage>,
+}
+
+/// Message content is either plain text or a list of content blocks
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum MessageContent {
+    Text(String),
+    #[serde(deserialize_with = "content_blocks")]
+    Blocks(Vec<ContentBlock>),
+    /// Anything else, kept so the rest of the entry still parses
+    Other(serde_json::Value),
+}
+
+impl Default for MessageContent {
+    fn default() -> Self {
+        Self::Blocks(Vec::new())
+    }
+}
+
+/// Parse blocks one by one, so a block of an unexpected shape becomes
+/// [`ContentBlock::Other`] instead of hiding the rest of the message
+fn content_blocks<'de, D>(deserializer: D) -> Result<Vec<ContentBlock>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let blocks = Vec::<serde_json::Value>::deserialize(deserializer)?;
+    Ok(blocks
+        .into_iter()
+        .map(|block| serde_json::from_value(block).unwrap_or(ContentBlock::Other))
+        .collect())
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ContentBlock {
+    Text {
+        #[serde(default)]
+        text: String,
+    },
+    Thinking {
+        #[serde(default)]
+        thinking: String,
+    },
+    RedactedThinking {},
+    ToolUse(ToolUse),
+    ToolResult(ToolResult),
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ToolUse {
+    #[serde(default)]
+    pub id: String,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub input: serde_json::Value,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ToolResult {
+    #[serde(default)]
+    pub tool_use_id: String,
+    #[serde(default)]
+    pub content: serde_json::Value,
+    #[serde(default)]
+    pub is_error: bool,
+}
+
+impl Entry {
+    /// Parse a single JSONL line, returning None for blank or malformed lines
+    pub fn parse(line: &str) -> Option<Self> {
+        let line = line.trim();
+        if line.is_empty() {
+            return None;
+        }
+        serde_json::from_str(line).ok()
+    }
+
+    pub fn kind(&self) -> EntryKind {
+        match self.r#type.as_deref() {
+            Some("user") => EntryKind::User,
+            Some("assistant") => EntryKind::Assistant,
+            Some("summary") => EntryKind::Summary,
+            Some("system") => EntryKind::System,
+            _ => EntryKind::Other,
+        }
+    }
+
+    pub fn is_assistant(&self) -> bool {
+        self.kind() == EntryKind::Assistant
+    }
+
+    /// Marker Claude Code writes where a conversation was compacted: a `compact_boundary`
+    /// system entry, or any entry carrying `compactMetadata`
+    pub fn is_compact_boundary(&self) -> bool {
+        (self.kind() == EntryKind::System && self.subtype.as_deref() == Some("compact_boundary"))
+            || self.compact_metadata.is_some()
+    }
+
+    /// Entry timestamp in UTC, when present and valid RFC 3339
+    pub fn time(&self) -> Option<DateTime<Utc>> {
+        let timestamp = self.timestamp.as_deref()?;
+        DateTime::parse_from_rfc3339(timestamp)
+            .ok()
+            .map(|dt| dt.with_timezone(&Utc))
+    }
+
+    /// Model that served this message
+    pub fn model(&self) -> Option<&str> {
+        self.message.as_ref()?.model.as_deref()
+    }
+
+    /// Normalized token usage of an assistant message
+    pub fn usage(&self) -> Option<NormalizedUsage> {
+        let usage = self.message.as_ref()?.usage.clone()?;
+        Some(usage.normalize())
+    }
+
+    /// Streaming writes one entry per content block, all sharing message id and request id
+    /// Entries with the same key carry the same usage and must only be counted once
+    pub fn dedupe_key(&self) -> Option<String> {
+        let message_id = self.message.as_ref()?.id.as_deref()?;
+        Some(format!(
+            "{}:{}",
+            message_id,
+            self.request_id.as_deref().unwrap_or_default()
+        ))
+    }
+
+    /// Content blocks of the message, empty for plain text content
+    pub fn content_blocks(&self) -> &[ContentBlock] {
+        match self.message.as_ref().map(|message| &message.content) {
+            Some(MessageContent::Blocks(blocks)) => blocks,
+            _ => &[],
+        }
+    }
+
+    /// A user entry typed by the person, as opposed to tool results or injected messages
+    pub fn is_user_prompt(&self) -> bool {
+        if self.kind() != EntryKind::User || self.is_meta || self.is_sidechain {
+            return false;
+        }
+        match self.message.as_ref().map(|message| &message.content) {
+            Some(MessageContent::Text(text)) => !text.trim().is_empty(),
+            Some(MessageContent::Blocks(blocks)) => blocks
+                .iter()
+                .any(|block| matches!(block, ContentBlock::Text { .. })),
+            _ => false,
+        }
+    }
+
+    pub fn tool_uses(&self) -> impl Iterator<Item = &ToolUse> {
+        self.content_blocks()
+            .iter()
+            .filter_map(|block| match block {
+                ContentBlock::ToolUse(tool_use) => Some(tool_use),
+                _ => None,
+            })
+    }
+
+    pub fn tool_results(&self) -> impl Iterator<Item = &ToolResult> {
+        self.content_blocks()
+            .iter()
+            .filter_map(|block| match block {
+                ContentBlock::ToolResult(tool_result) => Some(tool_result),
+                _ => None,
+            })
+    }
+}
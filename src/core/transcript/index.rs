@@ -0,0 +1,328 @@
+use super::{Entry, EntryKind};
+use crate::config::NormalizedUsage;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+
+/// Transcripts remembered in the index; the least recently used are dropped beyond this
+const MAX_INDEXED_FILES: usize = 64;
+
+/// Recent main-chain turns kept to average how much each turn grows the context
+const MAX_TURN_SAMPLES: usize = 10;
+
+/// What is known about a transcript up to `offset`
+/// Transcripts are append-only, so later renders only need to parse bytes after it
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FileState {
+    /// Byte offset just past the last complete line that was parsed
+    pub offset: u64,
+    /// Usage of the newest assistant message on the main conversation chain
+    pub last_usage: Option<NormalizedUsage>,
+    /// Usage of the newest subagent (sidechain) message since the last main-chain reply
+    pub subagent_usage: Option<NormalizedUsage>,
+    /// uuid of the newest main-chain entry
+    pub leaf_uuid: Option<String>,
+    /// Set when an entry did not continue from the previous leaf (rewind or compaction),
+    /// so `last_usage` must be recomputed by walking the new chain
+    pub chain_broken: bool,
+    /// leafUuid of the last entry when it is a summary
+    pub summary_leaf: Option<String>,
+    /// Dedupe key of the message `last_usage` came from
+    pub last_message_key: Option<String>,
+    /// Context growth of recent main-chain turns, oldest first
+    pub turn_growth: Vec<u32>,
+    pub updated_at: String,
+}
+
+impl FileState {
+    fn apply(&mut self, entry: &Entry) {
+        if entry.kind() == EntryKind::Summary {
+            self.summary_leaf = entry.leaf_uuid.clone();
+            return;
+        }
+        self.summary_leaf = None;
+
+        // Subagent messages never change the main thread's context
+        if entry.is_sidechain {
+            if entry.is_assistant() {
+                if let Some(usage) = entry.usage() {
+                    self.subagent_usage = Some(usage);
+                }
+            }
+            return;
+        }
+
+        if let Some(uuid) = &entry.uuid {
+            if self.leaf_uuid.is_some() && entry.parent_uuid != self.leaf_uuid {
+                self.chain_broken = true;
+            }
+            self.leaf_uuid = Some(uuid.clone());
+        }
+
+        if entry.is_assistant() {
+            if let Some(usage) = entry.usage() {
+                let key = entry.dedupe_key();
+                if key.is_none() || key != self.last_message_key {
+                    if let Some(previous) = &self.last_usage {
+                        self.record_turn(previous.display_tokens(), usage.display_tokens());
+                    }
+                    self.last_message_key = key;
+                }
+                self.last_usage = Some(usage);
+                self.subagent_usage = None;
+                self.chain_broken = false;
+            }
+        }
+    }
+
+    fn record_turn(&mut self, previous_tokens: u32, tokens: u32) {
+        // A shrinking context means a compaction, after which old turn sizes no longer apply
+        if tokens < previous_tokens {
+            self.turn_growth.clear();
+            return;
+        }
+        self.turn_growth.push(tokens - previous_tokens);
+        if self.turn_growth.len() > MAX_TURN_SAMPLES {
+            self.turn_growth.remove(0);
+        }
+    }
+
+    /// Average context growth per main-chain turn
+    pub fn average_turn_tokens(&self) -> Option<f64> {
+        if self.turn_growth.is_empty() {
+            return None;
+        }
+        let total: u64 = self.turn_growth.iter().map(|&t| t as u64).sum();
+        Some(total as f64 / self.turn_growth.len() as f64)
+    }
+}
+
+/// Offsets and latest usage per transcript, persisted between statusline renders
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct OffsetIndex {
+    files: HashMap<String, FileState>,
+    #[serde(skip)]
+    dirty: bool,
+}
+
+impl OffsetIndex {
+    pub fn get_index_path() -> Option<PathBuf> {
+        let home = dirs::home_dir()?;
+        Some(
+            home.join(".claude")
+                .join("ccline")
+                .join(".transcript_index.json"),
+        )
+    }
+
+    pub fn load() -> Self {
+        Self::get_index_path()
+            .and_then(|path| std::fs::read_to_string(path).ok())
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    /// Write the index back to disk when `update` changed anything
+    pub fn save(&mut self) {
+        if !self.dirty {
+            return;
+        }
+
+        // Keep the most recently updated files only
+        if self.files.len() > MAX_INDEXED_FILES {
+            let mut by_age: Vec<(String, String)> = self
+                .files
+                .iter()
+                .map(|(path, state)| (state.updated_at.clone(), path.clone()))
+                .collect();
+            by_age.sort();
+            let excess = self.files.len() - MAX_INDEXED_FILES;
+            for (_, path) in by_age.into_iter().take(excess) {
+                self.files.remove(&path);
+            }
+        }
+
+        if let Some(index_path) = Self::get_index_path() {
+            if let Some(parent) = index_path.parent() {
+                let _ = std::fs::create_dir_all(parent);
+            }
+            // Write a temp file per process and rename it, so concurrent renders never
+            // read a partly written index
+            if let Ok(json) = serde_json::to_string(self) {
+                let temp_path =
+                    index_path.with_extension(format!("json.{}.tmp", std::process::id()));
+                if std::fs::write(&temp_path, json).is_ok()
+                    && std::fs::rename(&temp_path, &index_path).is_err()
+                {
+                    let _ = std::fs::remove_file(&temp_path);
+                }
+            }
+        }
+    }
+
+    /// Bring the state for `path` up to date, parsing only bytes appended since the last call
+    /// Returns None when the file can't be read
+    pub fn update(&mut self, path: &Path) -> Option<FileState> {
+        let (state, changed) = self.current_state(path)?;
+        if changed {
+            self.files
+                .insert(path.to_string_lossy().into_owned(), state.clone());
+            self.dirty = true;
+        }
+        Some(state)
+    }
+
+    /// The up-to-date state for `path` without storing it, for files read only in passing
+    pub fn peek(&self, path: &Path) -> Option<FileState> {
+        self.current_state(path).map(|(state, _)| state)
+    }
+
+    /// State for `path` and whether it differs from the stored one
+    fn current_state(&self, path: &Path) -> Option<(FileState, bool)> {
+        let length = std::fs::metadata(path).ok()?.len();
+
+        let cached = self
+            .files
+            .get(path.to_string_lossy().as_ref())
+            .filter(|state| state.offset <= length)
+            .cloned();
+
+        let mut state = match cached {
+            Some(state) if state.offset == length => return Some((state, false)),
+            Some(state) => state,
+            // New or truncated file: start from the end instead of parsing everything
+            None => scan_from_end(path).ok()?,
+        };
+
+        read_appended(path, &mut state).ok()?;
+        if state.chain_broken {
+            state.last_usage = state
+                .leaf_uuid
+                .as_deref()
+                .and_then(|leaf| super::resolve_chain_usage(path, leaf));
+            state.chain_broken = false;
+        }
+        state.updated_at = chrono::Utc::now().to_rfc3339();
+        Some((state, true))
+    }
+}
+
+/// Parse complete lines after `state.offset`, advancing it past the last newline
+fn read_appended(path: &Path, state: &mut FileState) -> io::Result<()> {
+    let mut file = File::open(path)?;
+    file.seek(SeekFrom::Start(state.offset))?;
+    let mut reader = BufReader::new(file);
+
+    // Read raw bytes so offsets stay exact even when a line isn't valid UTF-8
+    let mut line = Vec::new();
+    loop {
+        line.clear();
+        let read = reader.read_until(b'\n', &mut line)?;
+        // Stop at EOF or at a line that is still being written
+        if read == 0 || line.last() != Some(&b'\n') {
+            break;
+        }
+        state.offset += read as u64;
+        if let Some(entry) = Entry::parse(&String::from_utf8_lossy(&line)) {
+            state.apply(&entry);
+        }
+    }
+
+    Ok(())
+}
+
+/// Build a state for a file seen for the first time by reading backwards along the main
+/// chain until its newest assistant usages, so the rest of the file is never parsed
+fn scan_from_end(path: &Path) -> io::Result<FileState> {
+    let mut state = FileState {
+        offset: complete_length(path)?,
+        ..Default::default()
+    };
+
+    // Newest first: the latest usage plus earlier turns to average their growth
+    let mut turns: Vec<(Option<String>, NormalizedUsage)> = Vec::new();
+    let mut target: Option<String> = None;
+
+    for (position, entry) in super::entries_rev(path)?.enumerate() {
+        if entry.kind() == EntryKind::Summary {
+            if position == 0 {
+                state.summary_leaf = entry.leaf_uuid.clone();
+                break;
+            }
+            continue;
+        }
+
+        if entry.is_sidechain {
+            if turns.is_empty() && state.subagent_usage.is_none() && entry.is_assistant() {
+                state.subagent_usage = entry.usage();
+            }
+            continue;
+        }
+
+        let uuid = match &entry.uuid {
+            Some(uuid) => uuid,
+            None => continue,
+        };
+        // The newest main-chain entry is the leaf; then follow its parents up the file
+        match &target {
+            None => state.leaf_uuid = Some(uuid.clone()),
+            Some(target) if target != uuid => continue,
+            Some(_) => {}
+        }
+
+        if entry.is_assistant() {
+            if let Some(usage) = entry.usage() {
+                let key = entry.dedupe_key();
+                let same_message = key.is_some() && turns.last().is_some_and(|(k, _)| *k == key);
+                if !same_message {
+                    turns.push((key, usage));
+                    if turns.len() > MAX_TURN_SAMPLES {
+                        break;
+                    }
+                }
+            }
+        }
+        match &entry.parent_uuid {
+            Some(parent) => target = Some(parent.clone()),
+            None => break,
+        }
+    }
+
+    if let Some((key, usage)) = turns.first() {
+        state.last_message_key = key.clone();
+        state.last_usage = Some(usage.clone());
+    }
+    for pair in turns.windows(2).rev() {
+        state.record_turn(pair[1].1.display_tokens(), pair[0].1.display_tokens());
+    }
+
+    Ok(state)
+}
+
+/// Length of the file up to and including its last newline
+fn complete_length(path: &Path) -> io::Result<u64> {
+    let mut lines = super::ReverseLines::open(path)?;
+    let length = lines.position();
+
+    let mut file = File::open(path)?;
+    let mut last = [0u8; 1];
+    if length == 0 {
+        return Ok(0);
+    }
+    file.seek(SeekFrom::Start(length - 1))?;
+    file.read_exact(&mut last)?;
+    if last[0] == b'\n' {
+        return Ok(length);
+    }
+
+    // Exclude a trailing partial line so it is parsed once complete; count its raw bytes,
+    // as invalid UTF-8 would change length once decoded
+    let partial = lines
+        .next_bytes()
+        .map(|line| line.len() as u64)
+        .unwrap_or(0);
+    Ok(length - partial)
+}
@@ -0,0 +1,237 @@
+// Typed access to Claude Code JSONL transcripts (~/.claude/projects/<project>/<session>.jsonl)
+// Stream with `entries`, read from the end with `entries_rev`,
+// and follow parentUuid / leafUuid chains with `EntryIndex`
+
+pub mod entry;
+pub mod index;
+pub mod reader;
+
+pub use entry::{
+    CompactMetadata, ContentBlock, Entry, EntryKind, Message, MessageContent, ToolResult, ToolUse,
+};
+pub use index::{FileState, OffsetIndex};
+pub use reader::{entries, entries_rev, Entries, MessageDeduper, ReverseEntries, ReverseLines};
+
+use crate::config::NormalizedUsage;
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Entries of one transcript keyed by uuid, for walking conversation chains
+#[derive(Debug, Default)]
+pub struct EntryIndex {
+    entries: HashMap<String, Entry>,
+}
+
+impl EntryIndex {
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let entries = entries(path)?
+            .filter_map(|entry| Some((entry.uuid.clone()?, entry)))
+            .collect();
+        Ok(Self { entries })
+    }
+
+    pub fn get(&self, uuid: &str) -> Option<&Entry> {
+        self.entries.get(uuid)
+    }
+
+    pub fn contains(&self, uuid: &str) -> bool {
+        self.entries.contains_key(uuid)
+    }
+
+    /// Walk from `uuid` up through `parentUuid` links, starting with the entry itself
+    pub fn ancestors<'a>(&'a self, uuid: &str) -> impl Iterator<Item = &'a Entry> + 'a {
+        let mut next = self.get(uuid);
+        // Bounded by the index size so a malformed cycle can't loop forever
+        let mut remaining = self.entries.len();
+
+        std::iter::from_fn(move || {
+            if remaining == 0 {
+                return None;
+            }
+            remaining -= 1;
+
+            let entry = next?;
+            next = entry
+                .parent_uuid
+                .as_deref()
+                .and_then(|parent| self.get(parent));
+            Some(entry)
+        })
+    }
+}
+
+/// Every `.jsonl` transcript in a project directory, most recently modified first
+pub fn project_transcripts(project_dir: &Path) -> Vec<PathBuf> {
+    let mut transcripts: Vec<PathBuf> = match fs::read_dir(project_dir) {
+        Ok(entries) => entries
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().and_then(|s| s.to_str()) == Some("jsonl"))
+            .collect(),
+        Err(_) => return Vec::new(),
+    };
+
+    transcripts.sort_by_key(|path| {
+        fs::metadata(path)
+            .and_then(|m| m.modified())
+            .unwrap_or(std::time::UNIX_EPOCH)
+    });
+    transcripts.reverse();
+    transcripts
+}
+
+/// Subagent transcripts that Claude Code writes for a session: under `<session>/subagents/`,
+/// or, in older versions, as `agent-*.jsonl` in the project directory shared by all sessions
+pub fn subagent_transcripts(path: &Path) -> Vec<PathBuf> {
+    let (project_dir, session_id) = match (path.parent(), path.file_stem()) {
+        (Some(dir), Some(stem)) => (dir, stem.to_string_lossy().into_owned()),
+        _ => return Vec::new(),
+    };
+
+    // The session's own directory needs no check of what is inside
+    let session_agents = agent_transcripts(&project_dir.join(&session_id).join("subagents"));
+    if !session_agents.is_empty() {
+        return session_agents;
+    }
+
+    // Files last written before the session started belong to other sessions; only the
+    // rest are opened to compare the sessionId of their first entry
+    let session_start: Option<std::time::SystemTime> = entries(path)
+        .ok()
+        .and_then(|mut entries| entries.find_map(|entry| entry.time()))
+        .map(Into::into);
+    agent_transcripts(project_dir)
+        .into_iter()
+        .filter(|candidate| match session_start {
+            Some(start) => fs::metadata(candidate)
+                .and_then(|m| m.modified())
+                .is_ok_and(|modified| modified >= start),
+            None => true,
+        })
+        .filter(|candidate| {
+            entries(candidate)
+                .ok()
+                .and_then(|mut entries| entries.next())
+                .and_then(|entry| entry.session_id)
+                .is_some_and(|id| id == session_id)
+        })
+        .collect()
+}
+
+/// `agent-*.jsonl` files in a directory
+fn agent_transcripts(dir: &Path) -> Vec<PathBuf> {
+    fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|candidate| {
+            let name = candidate
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            name.starts_with("agent-") && name.ends_with(".jsonl")
+        })
+        .collect()
+}
+
+/// Find the transcript in `project_dir` containing `uuid` and index it
+pub fn find_uuid_in_project(project_dir: &Path, uuid: &str) -> Option<(PathBuf, EntryIndex)> {
+    project_transcripts(project_dir)
+        .into_iter()
+        .find_map(|path| {
+            let index = EntryIndex::load(&path).ok()?;
+            index.contains(uuid).then_some((path, index))
+        })
+}
+
+/// Usage of the last assistant message at or above `leaf_uuid` in the project's transcripts
+/// Each file is read backwards and left as soon as the chain reaches an assistant message
+pub fn resolve_leaf_usage(project_dir: &Path, leaf_uuid: &str) -> Option<NormalizedUsage> {
+    project_transcripts(project_dir)
+        .iter()
+        .find_map(|path| resolve_chain_usage(path, leaf_uuid))
+}
+
+/// Walk the `parentUuid` chain from `leaf_uuid` up a single transcript to the nearest
+/// assistant usage, skipping messages from abandoned branches and subagents
+pub fn resolve_chain_usage(path: &Path, leaf_uuid: &str) -> Option<NormalizedUsage> {
+    // Parents are written before their children, so the chain only ever moves up the file
+    let mut target = leaf_uuid.to_string();
+
+    for line in ReverseLines::open(path).ok()? {
+        // Skip the JSON parse for lines that can't be the target
+        if !line.contains(target.as_str()) {
+            continue;
+        }
+        let entry = match Entry::parse(&line) {
+            Some(entry) if entry.uuid.as_deref() == Some(target.as_str()) => entry,
+            _ => continue,
+        };
+
+        if entry.is_assistant() {
+            if let Some(usage) = entry.usage() {
+                return Some(usage);
+            }
+        }
+        target = entry.parent_uuid?;
+    }
+
+    None
+}
+
+/// Latest usage of the main conversation and of its running subagent
+#[derive(Debug, Clone, Default)]
+pub struct ContextUsage {
+    pub main: Option<NormalizedUsage>,
+    pub subagent: Option<NormalizedUsage>,
+    /// Average context growth per main-chain turn
+    pub average_turn_tokens: Option<f64>,
+}
+
+/// Latest main-chain and subagent usage in a transcript
+/// When the transcript ends with a summary, its `leafUuid` is resolved across the project
+/// Progress is kept in the persisted [`OffsetIndex`], so repeated calls only parse new lines
+pub fn context_usage<P: AsRef<Path>>(path: P) -> Option<ContextUsage> {
+    let path = path.as_ref();
+
+    let mut index = OffsetIndex::load();
+    let state = index.update(path);
+    index.save();
+    usage_from_state(path, state?)
+}
+
+/// Usage from the newest transcript in a project that has any, for when the session's
+/// own transcript is missing. Those transcripts are read without being stored in the
+/// index, so they don't push active sessions out of it.
+pub fn project_context_usage(project_dir: &Path) -> Option<ContextUsage> {
+    let index = OffsetIndex::load();
+    project_transcripts(project_dir).iter().find_map(|path| {
+        index
+            .peek(path)
+            .and_then(|state| usage_from_state(path, state))
+            .filter(|usage| usage.main.is_some())
+    })
+}
+
+fn usage_from_state(path: &Path, state: FileState) -> Option<ContextUsage> {
+    let average_turn_tokens = state.average_turn_tokens();
+
+    let main = match &state.summary_leaf {
+        Some(leaf_uuid) => resolve_leaf_usage(path.parent()?, leaf_uuid),
+        None => state.last_usage,
+    };
+
+    Some(ContextUsage {
+        main,
+        average_turn_tokens,
+        subagent: state.subagent_usage,
+    })
+}
+
+/// Usage of the most recent main-chain assistant message in a transcript
+pub fn latest_usage<P: AsRef<Path>>(path: P) -> Option<NormalizedUsage> {
+    context_usage(path)?.main
+}
@@ -0,0 +1,155 @@
+use super::Entry;
+use std::collections::HashSet;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read, Seek, SeekFrom};
+use std::path::Path;
+
+/// Size of each block read from the end of a file
+const REVERSE_BLOCK_SIZE: u64 = 64 * 1024;
+
+/// Streams entries from the start of a transcript, skipping blank and malformed lines
+pub struct Entries {
+    reader: BufReader<File>,
+    line: Vec<u8>,
+}
+
+impl Iterator for Entries {
+    type Item = Entry;
+
+    fn next(&mut self) -> Option<Entry> {
+        loop {
+            // Read raw bytes so a line that isn't valid UTF-8 is skipped, not the rest of the file
+            self.line.clear();
+            match self.reader.read_until(b'\n', &mut self.line) {
+                Ok(0) | Err(_) => return None,
+                Ok(_) => {}
+            }
+            if let Some(entry) = Entry::parse(&String::from_utf8_lossy(&self.line)) {
+                return Some(entry);
+            }
+        }
+    }
+}
+
+/// Open a transcript for forward streaming
+pub fn entries<P: AsRef<Path>>(path: P) -> io::Result<Entries> {
+    let file = File::open(path)?;
+    Ok(Entries {
+        reader: BufReader::new(file),
+        line: Vec::new(),
+    })
+}
+
+/// Reads lines from the end of a file towards the start, one block at a time
+pub struct ReverseLines {
+    file: File,
+    /// Offset of the first byte not yet read into `buffer`
+    position: u64,
+    /// Bytes read so far that have not been returned, always starting at a line boundary
+    /// unless `position` is zero
+    buffer: Vec<u8>,
+}
+
+impl ReverseLines {
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let mut file = File::open(path)?;
+        let position = file.seek(SeekFrom::End(0))?;
+        Ok(Self {
+            file,
+            position,
+            buffer: Vec::new(),
+        })
+    }
+
+    /// Byte offset where the unread part of the file ends
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    /// The previous line as raw bytes, without its newline
+    pub fn next_bytes(&mut self) -> Option<Vec<u8>> {
+        loop {
+            // Skip the newline terminating the previous (later) line
+            if self.buffer.last() == Some(&b'\n') {
+                self.buffer.pop();
+            }
+
+            if let Some(index) = self.buffer.iter().rposition(|&b| b == b'\n') {
+                return Some(self.buffer.split_off(index + 1));
+            }
+
+            if self.position == 0 {
+                if self.buffer.is_empty() {
+                    return None;
+                }
+                return Some(std::mem::take(&mut self.buffer));
+            }
+
+            if self.read_block().is_err() {
+                return None;
+            }
+        }
+    }
+
+    fn read_block(&mut self) -> io::Result<()> {
+        let size = REVERSE_BLOCK_SIZE.min(self.position);
+        self.position -= size;
+        self.file.seek(SeekFrom::Start(self.position))?;
+
+        let mut block = vec![0; size as usize];
+        self.file.read_exact(&mut block)?;
+        block.append(&mut self.buffer);
+        self.buffer = block;
+        Ok(())
+    }
+}
+
+impl Iterator for ReverseLines {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        self.next_bytes()
+            .map(|line| String::from_utf8_lossy(&line).into_owned())
+    }
+}
+
+/// Streams entries from the end of a transcript, newest first
+pub struct ReverseEntries {
+    lines: ReverseLines,
+}
+
+impl Iterator for ReverseEntries {
+    type Item = Entry;
+
+    fn next(&mut self) -> Option<Entry> {
+        self.lines.by_ref().find_map(|line| Entry::parse(&line))
+    }
+}
+
+/// Open a transcript for backwards streaming, which only reads as much as is consumed
+pub fn entries_rev<P: AsRef<Path>>(path: P) -> io::Result<ReverseEntries> {
+    Ok(ReverseEntries {
+        lines: ReverseLines::open(path)?,
+    })
+}
+
+/// Tracks message keys so usage shared by streamed content blocks is counted once
+/// Keep one deduper across files to also skip history copied into resumed sessions
+#[derive(Debug, Default)]
+pub struct MessageDeduper {
+    seen: HashSet<String>,
+}
+
+impl MessageDeduper {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// True the first time a message is seen; entries without a message id always pass
+    pub fn first_seen(&mut self, entry: &Entry) -> bool {
+        match entry.dedupe_key() {
+            Some(key) => self.seen.insert(key),
+            None => true,
+        }
+    }
+}
@@ -1,6 +1,8 @@
 pub mod cli;
 pub mod config;
 pub mod core;
+pub mod render;
+pub mod report;
 pub mod ui;
 pub mod utils;
 
@@ -1,11 +1,20 @@
-use ccometixline::cli::Cli;
+use ccometixline::cli::{Cli, Command};
 use ccometixline::config::{Config, InputData};
 use ccometixline::core::{collect_all_segments, StatusLineGenerator};
-use std::io::{self, IsTerminal};
+use std::io::{self, IsTerminal, Read};
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let cli = Cli::parse_args();
 
+    // Handle subcommands
+    if let Some(command) = &cli.command {
+        match command {
+            Command::Report(args) => ccometixline::report::run(args)?,
+            Command::Render(args) => ccometixline::render::run(args)?,
+        }
+        return Ok(());
+    }
+
     // Handle configuration commands
     if cli.init {
         Config::init()?;
@@ -98,6 +107,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Load configuration
     let mut config = Config::load().unwrap_or_else(|_| Config::default());
+    // Recording follows the user's configuration, not the theme override
+    let recorder_config = config.recorder.clone();
 
     // Apply theme override if provided
     if let Some(theme) = cli.theme {
@@ -141,15 +152,37 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     }
 
     // Read Claude Code data from stdin
-    let stdin = io::stdin();
-    let input: InputData = serde_json::from_reader(stdin.lock())?;
+    let mut raw = String::new();
+    io::stdin().read_to_string(&mut raw)?;
+
+    // Record before parsing, so malformed input can be replayed too
+    ccometixline::core::recorder::record(&raw, &recorder_config);
+
+    // Malformed input still gets a statusline, built from whatever fields can be salvaged
+    let (input, parse_error) = InputData::parse_or_salvage(&raw);
+    if let Some(error) = &parse_error {
+        InputData::log_parse_error(&raw, error);
+    }
 
     // Collect segment data
     let segments_data = collect_all_segments(&config, &input);
 
-    // Render statusline
     let generator = StatusLineGenerator::new(config);
-    let statusline = generator.generate(segments_data);
+    if cli.json {
+        let mut output = generator.generate_json(segments_data);
+        if let Some(error) = &parse_error {
+            output["input_error"] = serde_json::Value::String(error.to_string());
+        }
+        println!("{}", serde_json::to_string_pretty(&output)?);
+        return Ok(());
+    }
+
+    // Render statusline
+    let statusline = if parse_error.is_some() {
+        generator.generate_degraded(segments_data)
+    } else {
+        generator.generate(segments_data)
+    };
 
     println!("{}", statusline);
 
@@ -0,0 +1,89 @@
+use crate::cli::RenderArgs;
+use crate::config::{Config, ConfigLoader, InputData};
+use crate::core::recorder::{self, Recording};
+use crate::core::{collect_all_segments, StatusLineGenerator};
+use std::path::PathBuf;
+
+/// Run `ccline render`: re-render saved statusline inputs
+pub fn run(args: &RenderArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let path = match &args.input {
+        Some(input) => PathBuf::from(input),
+        None => recorder::get_log_path().ok_or("Could not locate ~/.claude/ccline")?,
+    };
+    if !path.exists() {
+        return Err(if args.replay {
+            format!(
+                "No recorded inputs at {}; enable [recorder] in the config first",
+                path.display()
+            )
+        } else {
+            format!("Input file not found: {}", path.display())
+        }
+        .into());
+    }
+
+    let mut recordings = recorder::load(&path)?;
+    if recordings.is_empty() {
+        return Err(format!("No statusline inputs found in {}", path.display()).into());
+    }
+    if let Some(last) = args.last {
+        let skip = recordings.len().saturating_sub(last);
+        recordings.drain(..skip);
+    }
+
+    let config = match (&args.config, &args.theme) {
+        (Some(config_path), _) => ConfigLoader::load_from_path(config_path)?,
+        (None, Some(theme)) => crate::ui::themes::ThemePresets::get_theme(theme),
+        (None, None) => Config::load().unwrap_or_else(|_| Config::default()),
+    };
+    let generator = StatusLineGenerator::new(config.clone());
+
+    if args.json {
+        let output: Vec<serde_json::Value> = recordings
+            .iter()
+            .map(|recording| {
+                let (input, parse_error) = recording.input_data();
+                let mut statusline = generator.generate_json(collect_all_segments(&config, &input));
+                if let Some(error) = &parse_error {
+                    statusline["input_error"] = serde_json::Value::String(error.to_string());
+                }
+                serde_json::json!({
+                    "recorded_at": recording.recorded_at,
+                    "transcript_path": input.transcript_path,
+                    "statusline": statusline,
+                })
+            })
+            .collect();
+        println!("{}", serde_json::to_string_pretty(&output)?);
+        return Ok(());
+    }
+
+    // A single input prints the bare statusline, so output can be diffed between configs
+    let show_headers = recordings.len() > 1;
+    for recording in &recordings {
+        let (input, parse_error) = recording.input_data();
+        if show_headers {
+            println!("\x1b[2m{}\x1b[0m", header(recording, &input));
+        }
+
+        // Malformed inputs render degraded, as they did when received
+        let segments = collect_all_segments(&config, &input);
+        if parse_error.is_some() {
+            println!("{}", generator.generate_degraded(segments));
+        } else {
+            println!("{}", generator.generate(segments));
+        }
+    }
+
+    Ok(())
+}
+
+/// "<time> · <transcript path>", naming which input a statusline came from
+fn header(recording: &Recording, input: &InputData) -> String {
+    let time = if recording.recorded_at.is_empty() {
+        "-"
+    } else {
+        &recording.recorded_at
+    };
+    format!("{} · {}", time, input.transcript_path)
+}
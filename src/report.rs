@@ -0,0 +1,168 @@
+use crate::cli::{ReportArgs, ReportFormat, ReportGroup};
+use crate::config::ModelConfig;
+use crate::core::spend::{self, format_tokens, GroupBy, SpendRow};
+use chrono::{Local, NaiveDate, NaiveTime, TimeZone, Utc};
+use std::io::{self, Write};
+
+/// Run `ccline report`: aggregate usage across all projects and print it
+pub fn run(args: &ReportArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let since = match &args.since {
+        Some(date) => {
+            let date = NaiveDate::parse_from_str(date, "%Y-%m-%d")
+                .map_err(|_| format!("Invalid --since date '{}', expected YYYY-MM-DD", date))?;
+            let midnight = date.and_time(NaiveTime::MIN);
+            let local = Local
+                .from_local_datetime(&midnight)
+                .earliest()
+                .ok_or("Invalid local date")?;
+            Some(local.with_timezone(&Utc))
+        }
+        None => None,
+    };
+
+    let projects_dir = spend::claude_projects_dir().ok_or("Could not locate ~/.claude/projects")?;
+    let model_config = ModelConfig::load();
+    let records = spend::collect_usage_records(&projects_dir, since, &model_config);
+
+    let group_by = match args.by {
+        ReportGroup::Day => GroupBy::Day,
+        ReportGroup::Week => GroupBy::Week,
+        ReportGroup::Month => GroupBy::Month,
+        ReportGroup::Project => GroupBy::Project,
+        ReportGroup::Model => GroupBy::Model,
+    };
+
+    let rows = spend::aggregate(&records, group_by);
+    let total = total_row(&rows);
+
+    let mut out = io::stdout().lock();
+    let written = match args.format {
+        ReportFormat::Table => print_table(&mut out, &rows, &total, args.by),
+        ReportFormat::Json => {
+            let output = serde_json::json!({
+                "group_by": format!("{:?}", args.by).to_lowercase(),
+                "rows": rows,
+                "total": total,
+            });
+            writeln!(out, "{}", serde_json::to_string_pretty(&output)?)
+        }
+        ReportFormat::Csv => print_csv(&mut out, &rows),
+    };
+
+    // Output piped into e.g. `head` may be closed early, which isn't an error
+    match written.and_then(|_| out.flush()) {
+        Err(error) if error.kind() != io::ErrorKind::BrokenPipe => Err(error.into()),
+        _ => Ok(()),
+    }
+}
+
+fn total_row(rows: &[SpendRow]) -> SpendRow {
+    let mut total = SpendRow {
+        key: "Total".to_string(),
+        ..Default::default()
+    };
+    for row in rows {
+        total.messages += row.messages;
+        total.input_tokens += row.input_tokens;
+        total.output_tokens += row.output_tokens;
+        total.cache_creation_tokens += row.cache_creation_tokens;
+        total.cache_read_tokens += row.cache_read_tokens;
+        total.total_tokens += row.total_tokens;
+        total.cost_usd += row.cost_usd;
+        total.unpriced_messages += row.unpriced_messages;
+    }
+    total
+}
+
+fn print_table(
+    out: &mut dyn Write,
+    rows: &[SpendRow],
+    total: &SpendRow,
+    by: ReportGroup,
+) -> io::Result<()> {
+    if rows.is_empty() {
+        return writeln!(out, "No usage found");
+    }
+
+    let key_header = match by {
+        ReportGroup::Day => "Date",
+        ReportGroup::Week => "Week",
+        ReportGroup::Month => "Month",
+        ReportGroup::Project => "Project",
+        ReportGroup::Model => "Model",
+    };
+
+    let key_width = rows
+        .iter()
+        .map(|row| row.key.chars().count())
+        .chain([key_header.len(), total.key.len()])
+        .max()
+        .unwrap_or(0);
+
+    let print_row = |out: &mut dyn Write, key: &str, cells: [String; 6]| {
+        writeln!(
+            out,
+            "{:<key_width$}  {:>8}  {:>8}  {:>11}  {:>10}  {:>8}  {:>10}",
+            key, cells[0], cells[1], cells[2], cells[3], cells[4], cells[5],
+        )
+    };
+    let row_cells = |row: &SpendRow| {
+        [
+            format_tokens(row.input_tokens),
+            format_tokens(row.output_tokens),
+            format_tokens(row.cache_creation_tokens),
+            format_tokens(row.cache_read_tokens),
+            format_tokens(row.total_tokens),
+            format!("${:.2}", row.cost_usd),
+        ]
+    };
+
+    print_row(
+        out,
+        key_header,
+        [
+            "Input",
+            "Output",
+            "Cache Write",
+            "Cache Read",
+            "Total",
+            "Cost",
+        ]
+        .map(String::from),
+    )?;
+    writeln!(out, "{}", "─".repeat(key_width + 67))?;
+    for row in rows {
+        print_row(out, &row.key, row_cells(row))?;
+    }
+    writeln!(out, "{}", "─".repeat(key_width + 67))?;
+    print_row(out, &total.key, row_cells(total))?;
+
+    if total.unpriced_messages > 0 {
+        writeln!(
+            out,
+            "\n{} messages had no pricing in models.toml and are excluded from cost",
+            total.unpriced_messages
+        )?;
+    }
+    Ok(())
+}
+
+fn print_csv(out: &mut dyn Write, rows: &[SpendRow]) -> io::Result<()> {
+    writeln!(out, "key,messages,input_tokens,output_tokens,cache_creation_tokens,cache_read_tokens,total_tokens,cost_usd,unpriced_messages")?;
+    for row in rows {
+        writeln!(
+            out,
+            "\"{}\",{},{},{},{},{},{},{:.6},{}",
+            row.key.replace('"', "\"\""),
+            row.messages,
+            row.input_tokens,
+            row.output_tokens,
+            row.cache_creation_tokens,
+            row.cache_read_tokens,
+            row.total_tokens,
+            row.cost_usd,
+            row.unpriced_messages
+        )?;
+    }
+    Ok(())
+}
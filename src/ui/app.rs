@@ -471,7 +471,7 @@ impl App {
             Panel::Settings => {
                 // Check segment type to determine field count
                 let is_usage_segment = self.config.segments.get(self.selected_segment)
-                    .map(|s| matches!(s.id, crate::config::SegmentId::Usage5Hour | crate::config::SegmentId::Usage7Day | crate::config::SegmentId::ContextWindow))
+                    .map(|s| matches!(s.id, crate::config::SegmentId::Usage5Hour | crate::config::SegmentId::Usage7Day | crate::config::SegmentId::ContextWindow | crate::config::SegmentId::Cost | crate::config::SegmentId::Spend))
                     .unwrap_or(false);
 
                 let is_git_segment = self.config.segments.get(self.selected_segment)
@@ -546,6 +546,19 @@ impl App {
                         SegmentId::Session => "Session",
                         SegmentId::OutputStyle => "Output Style",
                         SegmentId::Update => "Update",
+                        SegmentId::Time => "Time",
+                        SegmentId::BurnRate => "Burn Rate",
+                        SegmentId::Spend => "Spend",
+                        SegmentId::CacheHit => "Cache Hit",
+                        SegmentId::Compaction => "Compaction",
+                        SegmentId::ToolActivity => "Tool Activity",
+                        SegmentId::Todos => "Todos",
+                        SegmentId::Subagents => "Subagents",
+                        SegmentId::FilesTouched => "Files Touched",
+                        SegmentId::TurnStats => "Turn Stats",
+                        SegmentId::Thinking => "Thinking",
+                        SegmentId::ClaudeVersion => "Claude Version",
+                        SegmentId::SessionId => "Session ID",
                     };
                     let is_enabled = segment.enabled;
                     self.status_message = Some(format!(
@@ -575,6 +588,19 @@ impl App {
                                 SegmentId::Session => "Session",
                                 SegmentId::OutputStyle => "Output Style",
                                 SegmentId::Update => "Update",
+                                SegmentId::Time => "Time",
+                                SegmentId::BurnRate => "Burn Rate",
+                                SegmentId::Spend => "Spend",
+                                SegmentId::CacheHit => "Cache Hit",
+                                SegmentId::Compaction => "Compaction",
+                                SegmentId::ToolActivity => "Tool Activity",
+                                SegmentId::Todos => "Todos",
+                                SegmentId::Subagents => "Subagents",
+                                SegmentId::FilesTouched => "Files Touched",
+                                SegmentId::TurnStats => "Turn Stats",
+                                SegmentId::Thinking => "Thinking",
+                                SegmentId::ClaudeVersion => "Claude Version",
+                                SegmentId::SessionId => "Session ID",
                             };
                             let is_enabled = segment.enabled;
                             self.status_message = Some(format!(
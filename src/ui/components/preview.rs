@@ -1,6 +1,9 @@
 use crate::config::{Config, SegmentId};
-use crate::core::segments::SegmentData;
+use crate::core::segments::progress_bar::ProgressBar;
+use crate::core::segments::{threshold_utils, SegmentData};
 use crate::core::StatusLineGenerator;
+use crate::utils::TimeFormatter;
+use chrono::{Local, TimeZone};
 use ratatui::{
     layout::Rect,
     text::{Line, Text},
@@ -115,6 +118,31 @@ impl PreviewComponent {
         }
     }
 
+    /// Apply budget threshold colors to a mock spend, returning the remaining text when shown
+    fn apply_mock_budget(
+        &self,
+        segment_config: &crate::config::SegmentConfig,
+        spent_usd: f64,
+        metadata: &mut HashMap<String, String>,
+    ) -> Option<String> {
+        let budget = segment_config
+            .options
+            .get("budget_usd")
+            .and_then(|v| v.as_f64())
+            .filter(|budget| *budget > 0.0)?;
+
+        if let Some(color_override) = self.get_threshold_color(segment_config, spent_usd / budget * 100.0) {
+            metadata.insert("text_color_override".to_string(), color_override);
+        }
+
+        let show_remaining = segment_config
+            .options
+            .get("show_remaining")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+        show_remaining.then(|| threshold_utils::format_budget_remaining(spent_usd, budget))
+    }
+
     /// Generate mock segments data for preview display
     /// This creates perfect preview data without depending on real environment
     fn generate_mock_segments_data(
@@ -128,7 +156,7 @@ impl PreviewComponent {
                 continue;
             }
 
-            let mock_data = match segment_config.id {
+            let mut mock_data = match segment_config.id {
                 SegmentId::Model => SegmentData {
                     primary: "Sonnet 4".to_string(),
                     secondary: "".to_string(),
@@ -197,7 +225,16 @@ impl PreviewComponent {
                 },
                 SegmentId::ContextWindow => SegmentData {
                     primary: "78.2%".to_string(),
-                    secondary: "· 156.4k".to_string(),
+                    secondary: if segment_config
+                        .options
+                        .get("show_forecast")
+                        .and_then(|v| v.as_bool())
+                        .unwrap_or(false)
+                    {
+                        "· 156.4k ~0 turns left".to_string()
+                    } else {
+                        "· 156.4k".to_string()
+                    },
                     metadata: {
                         let mut map = HashMap::new();
                         map.insert("total_tokens".to_string(), "156400".to_string());
@@ -206,10 +243,18 @@ impl PreviewComponent {
                         map
                     },
                 },
-                SegmentId::Usage => SegmentData {
-                    primary: "24%".to_string(),
-                    secondary: "· 10-7-2".to_string(),
-                    metadata: HashMap::new(),
+                SegmentId::Usage => {
+                    let countdown = segment_config
+                        .options
+                        .get("reset_format")
+                        .and_then(|v| v.as_str())
+                        == Some("countdown");
+
+                    SegmentData {
+                        primary: "24%".to_string(),
+                        secondary: if countdown { "· in 3d4h" } else { "· → Oct 7, 2am" }.to_string(),
+                        metadata: HashMap::new(),
+                    }
                 },
                 SegmentId::Usage5Hour => {
                     // Use mock utilization that demonstrates warning threshold (65% > default 60%)
@@ -223,9 +268,41 @@ impl PreviewComponent {
                         metadata.insert("text_color_override".to_string(), color_override);
                     }
 
+                    let countdown = segment_config
+                        .options
+                        .get("reset_format")
+                        .and_then(|v| v.as_str())
+                        == Some("countdown");
+                    let mut secondary = if countdown { "in 2h13m" } else { "→ 11am" }.to_string();
+
+                    // Local estimates without a token limit show the block's spend instead
+                    let local = segment_config
+                        .options
+                        .get("source")
+                        .and_then(|v| v.as_str())
+                        == Some("local");
+                    let has_limit = segment_config
+                        .options
+                        .get("token_limit")
+                        .and_then(|v| v.as_u64())
+                        .is_some_and(|limit| limit > 0);
+                    let primary = match (local, has_limit) {
+                        (true, false) => "~$1.84".to_string(),
+                        (true, true) => "~65%".to_string(),
+                        (false, _) => "65%".to_string(),
+                    };
+                    let show_projection = segment_config
+                        .options
+                        .get("show_projection")
+                        .and_then(|v| v.as_bool())
+                        .unwrap_or(false);
+                    if local && show_projection {
+                        secondary.push_str(if has_limit { " · proj ~4.2M" } else { " · proj ~$4.10" });
+                    }
+
                     SegmentData {
-                        primary: "65%".to_string(),
-                        secondary: "→ 11am".to_string(),
+                        primary,
+                        secondary,
                         metadata,
                     }
                 },
@@ -241,20 +318,28 @@ impl PreviewComponent {
                         metadata.insert("text_color_override".to_string(), color_override);
                     }
 
+                    let countdown = segment_config
+                        .options
+                        .get("reset_format")
+                        .and_then(|v| v.as_str())
+                        == Some("countdown");
+
                     SegmentData {
                         primary: "85%".to_string(),
-                        secondary: "→ Oct 9:5am".to_string(),
+                        secondary: if countdown { "in 3d4h" } else { "→ Oct 9, 5am" }.to_string(),
                         metadata,
                     }
                 },
-                SegmentId::Cost => SegmentData {
-                    primary: "$0.02".to_string(),
-                    secondary: "".to_string(),
-                    metadata: {
-                        let mut map = HashMap::new();
-                        map.insert("cost".to_string(), "0.01234".to_string());
-                        map
-                    },
+                SegmentId::Cost => {
+                    let mut metadata = HashMap::new();
+                    metadata.insert("cost".to_string(), "0.01234".to_string());
+                    let secondary = self.apply_mock_budget(segment_config, 0.01234, &mut metadata);
+
+                    SegmentData {
+                        primary: "$0.02".to_string(),
+                        secondary: secondary.unwrap_or_default(),
+                        metadata,
+                    }
                 },
                 SegmentId::Session => SegmentData {
                     primary: "3m45s".to_string(),
@@ -289,8 +374,298 @@ impl PreviewComponent {
                         map
                     },
                 },
+                SegmentId::Time => {
+                    // Fixed mock time so the preview reflects the 12h/24h and locale settings
+                    let formatter = TimeFormatter::new(&config.time);
+                    let mock_time = Local
+                        .with_ymd_and_hms(2025, 10, 9, 14, 32, 0)
+                        .single()
+                        .unwrap_or_else(Local::now);
+                    let pattern = segment_config
+                        .options
+                        .get("format")
+                        .and_then(|v| v.as_str())
+                        .filter(|f| !f.is_empty())
+                        .unwrap_or_else(|| formatter.time_pattern());
+                    let time = formatter.format(&mock_time, pattern);
+
+                    SegmentData {
+                        primary: time.clone(),
+                        secondary: "".to_string(),
+                        metadata: {
+                            let mut map = HashMap::new();
+                            map.insert("time".to_string(), time);
+                            map.insert("timezone".to_string(), "local".to_string());
+                            map
+                        },
+                    }
+                }
+                SegmentId::BurnRate => {
+                    // Use mock rate that stays below the default warning threshold
+                    let rate = 1840.0;
+                    let mut metadata = HashMap::new();
+                    metadata.insert("tokens_per_minute".to_string(), rate.to_string());
+                    metadata.insert("input_per_minute".to_string(), "1210".to_string());
+                    metadata.insert("output_per_minute".to_string(), "630".to_string());
+
+                    if let Some(color_override) = self.get_threshold_color(segment_config, rate) {
+                        metadata.insert("text_color_override".to_string(), color_override);
+                    }
+
+                    SegmentData {
+                        primary: "1.8k/min".to_string(),
+                        secondary: "↑1.2k ↓630".to_string(),
+                        metadata,
+                    }
+                }
+                SegmentId::Spend => {
+                    let mut metadata = HashMap::new();
+                    metadata.insert("today_usd".to_string(), "4.2".to_string());
+                    metadata.insert("week_usd".to_string(), "31.0".to_string());
+                    let secondary = match self.apply_mock_budget(segment_config, 4.2, &mut metadata) {
+                        Some(remaining) => format!("· {} · week $31.00", remaining),
+                        None => "· week $31.00".to_string(),
+                    };
+
+                    SegmentData {
+                        primary: "today $4.20".to_string(),
+                        secondary,
+                        metadata,
+                    }
+                },
+                SegmentId::CacheHit => {
+                    // Mock a healthy cache; thresholds apply to the 8% miss rate
+                    let mut metadata = HashMap::new();
+                    metadata.insert("last_turn_hit_ratio".to_string(), "92".to_string());
+                    metadata.insert("session_hit_ratio".to_string(), "87".to_string());
+                    if let Some(color_override) = self.get_threshold_color(segment_config, 8.0) {
+                        metadata.insert("text_color_override".to_string(), color_override);
+                    }
+
+                    let show_session = segment_config
+                        .options
+                        .get("show_session")
+                        .and_then(|v| v.as_bool())
+                        .unwrap_or(true);
+                    let show_savings = segment_config
+                        .options
+                        .get("show_savings")
+                        .and_then(|v| v.as_bool())
+                        .unwrap_or(true);
+                    let mut details = Vec::new();
+                    if show_session {
+                        details.push("session 87%");
+                    }
+                    if show_savings {
+                        details.push("saved $1.26");
+                    }
+
+                    SegmentData {
+                        primary: "92%".to_string(),
+                        secondary: if details.is_empty() {
+                            String::new()
+                        } else {
+                            format!("· {}", details.join(" · "))
+                        },
+                        metadata,
+                    }
+                },
+                SegmentId::Compaction => {
+                    // Mock two compactions 48 minutes apart, inside the warning interval
+                    let mut metadata = HashMap::new();
+                    metadata.insert("compaction_count".to_string(), "2".to_string());
+                    metadata.insert("last_compaction_tokens".to_string(), "158000".to_string());
+                    metadata.insert("last_interval_minutes".to_string(), "48".to_string());
+                    let warning_minutes = segment_config
+                        .options
+                        .get("warning_interval_minutes")
+                        .and_then(|v| v.as_u64())
+                        .unwrap_or(60);
+                    if 48 < warning_minutes {
+                        if let Some(color) = segment_config.options.get("warning_color") {
+                            metadata.insert("text_color_override".to_string(), color.to_string());
+                        }
+                    }
+                    SegmentData {
+                        primary: "2×".to_string(),
+                        secondary: "· at 158k · 35m ago".to_string(),
+                        metadata,
+                    }
+                },
+                SegmentId::ToolActivity => {
+                    let option_bool = |key: &str| {
+                        segment_config
+                            .options
+                            .get(key)
+                            .and_then(|v| v.as_bool())
+                            .unwrap_or(true)
+                    };
+                    let mut parts = Vec::new();
+                    if option_bool("show_last") {
+                        parts.push("Bash cargo test");
+                    }
+                    if option_bool("show_total") {
+                        parts.push("42 calls");
+                    }
+                    if option_bool("show_by_tool") {
+                        parts.push("Edit 14 Read 11 Bash 9");
+                    }
+                    if option_bool("show_errors") {
+                        parts.push("2 failed");
+                    }
+                    let primary = if parts.is_empty() {
+                        String::new()
+                    } else {
+                        parts.remove(0).to_string()
+                    };
+
+                    let mut metadata = HashMap::new();
+                    metadata.insert("total_calls".to_string(), "42".to_string());
+                    metadata.insert("failed_calls".to_string(), "2".to_string());
+                    SegmentData {
+                        primary,
+                        secondary: if parts.is_empty() {
+                            String::new()
+                        } else {
+                            format!("· {}", parts.join(" · "))
+                        },
+                        metadata,
+                    }
+                },
+                SegmentId::Todos => {
+                    let mut metadata = HashMap::new();
+                    metadata.insert("total".to_string(), "7".to_string());
+                    metadata.insert("completed".to_string(), "3".to_string());
+                    metadata.insert("percentage".to_string(), "42.9".to_string());
+                    let show_current = segment_config
+                        .options
+                        .get("show_current")
+                        .and_then(|v| v.as_bool())
+                        .unwrap_or(true);
+                    SegmentData {
+                        primary: "3/7".to_string(),
+                        secondary: if show_current {
+                            "· Writing integration tests".to_string()
+                        } else {
+                            String::new()
+                        },
+                        metadata,
+                    }
+                },
+                SegmentId::Subagents => {
+                    let option_bool = |key: &str| {
+                        segment_config
+                            .options
+                            .get(key)
+                            .and_then(|v| v.as_bool())
+                            .unwrap_or(true)
+                    };
+                    let mut details = Vec::new();
+                    if option_bool("show_descriptions") {
+                        details.push("Explore auth flow, Review tests");
+                    }
+                    if option_bool("show_tokens") {
+                        details.push("184.2k tokens");
+                    }
+
+                    let mut metadata = HashMap::new();
+                    metadata.insert("running".to_string(), "2".to_string());
+                    metadata.insert("subagent_tokens".to_string(), "184200".to_string());
+                    SegmentData {
+                        primary: "2 running".to_string(),
+                        secondary: if details.is_empty() {
+                            String::new()
+                        } else {
+                            format!("· {}", details.join(" · "))
+                        },
+                        metadata,
+                    }
+                },
+                SegmentId::FilesTouched => {
+                    let show_last = segment_config
+                        .options
+                        .get("show_last")
+                        .and_then(|v| v.as_bool())
+                        .unwrap_or(true);
+                    let mut metadata = HashMap::new();
+                    metadata.insert("count".to_string(), "7".to_string());
+                    SegmentData {
+                        primary: "7 files".to_string(),
+                        secondary: if show_last {
+                            "· statusline.rs".to_string()
+                        } else {
+                            String::new()
+                        },
+                        metadata,
+                    }
+                },
+                SegmentId::TurnStats => {
+                    let format = segment_config
+                        .options
+                        .get("format")
+                        .and_then(|v| v.as_str())
+                        .unwrap_or("{prompts} · {avg_latency}/turn · {since_prompt} ago");
+                    let primary = format
+                        .replace("{prompts}", "12 prompts")
+                        .replace("{user_turns}", "12")
+                        .replace("{assistant_turns}", "48")
+                        .replace("{avg_latency}", "6s")
+                        .replace("{since_prompt}", "3m")
+                        .replace("{api_ratio}", "41%");
+
+                    let mut metadata = HashMap::new();
+                    metadata.insert("user_turns".to_string(), "12".to_string());
+                    metadata.insert("assistant_turns".to_string(), "48".to_string());
+                    SegmentData {
+                        primary,
+                        secondary: String::new(),
+                        metadata,
+                    }
+                },
+                SegmentId::Thinking => {
+                    let mut metadata = HashMap::new();
+                    metadata.insert("thinking_used".to_string(), "true".to_string());
+                    metadata.insert("thinking_blocks".to_string(), "2".to_string());
+                    SegmentData {
+                        primary: "~1.8k tokens".to_string(),
+                        secondary: "· 2 blocks".to_string(),
+                        metadata,
+                    }
+                },
+                SegmentId::ClaudeVersion => {
+                    let mut metadata = HashMap::new();
+                    metadata.insert("version".to_string(), "1.0.88".to_string());
+                    SegmentData {
+                        primary: "v1.0.88".to_string(),
+                        secondary: String::new(),
+                        metadata,
+                    }
+                },
+                SegmentId::SessionId => {
+                    let session_id = "3f2a9c1e-7b4d-4e8a-9c2f-1d5e6b7a8c9d";
+                    let length = segment_config
+                        .options
+                        .get("length")
+                        .and_then(|v| v.as_u64())
+                        .unwrap_or(8) as usize;
+                    let mut metadata = HashMap::new();
+                    metadata.insert("session_id".to_string(), session_id.to_string());
+                    SegmentData {
+                        primary: if length > 0 {
+                            session_id.chars().take(length).collect()
+                        } else {
+                            session_id.to_string()
+                        },
+                        secondary: String::new(),
+                        metadata,
+                    }
+                },
             };
 
+            if let Some(bar) = ProgressBar::from_options(&segment_config.options) {
+                bar.apply(&mut mock_data);
+            }
+
             segments_data.push((segment_config.clone(), mock_data));
         }
 
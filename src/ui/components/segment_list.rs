@@ -69,6 +69,19 @@ impl SegmentListComponent {
                     SegmentId::Session => "Session",
                     SegmentId::OutputStyle => "Output Style",
                     SegmentId::Update => "Update",
+                    SegmentId::Time => "Time",
+                    SegmentId::BurnRate => "Burn Rate",
+                    SegmentId::Spend => "Spend",
+                    SegmentId::CacheHit => "Cache Hit",
+                    SegmentId::Compaction => "Compaction",
+                    SegmentId::ToolActivity => "Tool Activity",
+                    SegmentId::Todos => "Todos",
+                    SegmentId::Subagents => "Subagents",
+                    SegmentId::FilesTouched => "Files Touched",
+                    SegmentId::TurnStats => "Turn Stats",
+                    SegmentId::Thinking => "Thinking",
+                    SegmentId::ClaudeVersion => "Claude Version",
+                    SegmentId::SessionId => "Session ID",
                 };
 
                 if is_selected {
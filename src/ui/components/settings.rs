@@ -39,6 +39,19 @@ impl SettingsComponent {
                 SegmentId::Session => "Session",
                 SegmentId::OutputStyle => "Output Style",
                 SegmentId::Update => "Update",
+                SegmentId::Time => "Time",
+                SegmentId::BurnRate => "Burn Rate",
+                SegmentId::Spend => "Spend",
+                SegmentId::CacheHit => "Cache Hit",
+                SegmentId::Compaction => "Compaction",
+                SegmentId::ToolActivity => "Tool Activity",
+                SegmentId::Todos => "Todos",
+                SegmentId::Subagents => "Subagents",
+                SegmentId::FilesTouched => "Files Touched",
+                SegmentId::TurnStats => "Turn Stats",
+                SegmentId::Thinking => "Thinking",
+                SegmentId::ClaudeVersion => "Claude Version",
+                SegmentId::SessionId => "Session ID",
             };
             let current_icon = match config.style.mode {
                 StyleMode::Plain => &segment.icon.plain,
@@ -47,11 +60,11 @@ impl SettingsComponent {
             // Convert AnsiColor to ratatui Color using shared helper
             let icon_ratatui_color = segment.colors.icon
                 .as_ref()
-                .map(|c| color_utils::ansi_color_to_ratatui(c))
+                .map(color_utils::ansi_color_to_ratatui)
                 .unwrap_or(Color::White);
             let text_ratatui_color = segment.colors.text
                 .as_ref()
-                .map(|c| color_utils::ansi_color_to_ratatui(c))
+                .map(color_utils::ansi_color_to_ratatui)
                 .unwrap_or(Color::White);
             let icon_color_desc = match &segment.colors.icon {
                 Some(crate::config::AnsiColor::Color16 { c16 }) => match c16 {
@@ -107,7 +120,7 @@ impl SettingsComponent {
             };
             let background_ratatui_color = segment.colors.background
                 .as_ref()
-                .map(|c| color_utils::ansi_color_to_ratatui(c))
+                .map(color_utils::ansi_color_to_ratatui)
                 .unwrap_or(Color::White);
             let background_color_desc = match &segment.colors.background {
                 Some(crate::config::AnsiColor::Color16 { c16 }) => match c16 {
@@ -155,7 +168,11 @@ impl SettingsComponent {
             // Check if this is a usage segment to show threshold fields
             let is_usage_segment = matches!(
                 segment.id,
-                SegmentId::Usage5Hour | SegmentId::Usage7Day | SegmentId::ContextWindow
+                SegmentId::Usage5Hour
+                    | SegmentId::Usage7Day
+                    | SegmentId::ContextWindow
+                    | SegmentId::Cost
+                    | SegmentId::Spend
             );
 
             // Check if this is a Git segment to show Git-specific options
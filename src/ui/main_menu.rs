@@ -75,9 +75,7 @@ impl MainMenu {
                         self.should_quit = true;
                     }
                     KeyCode::Up => {
-                        if self.selected_item > 0 {
-                            self.selected_item -= 1;
-                        }
+                        self.selected_item = self.selected_item.saturating_sub(1);
                     }
                     KeyCode::Down => {
                         let menu_items = self.get_menu_items();
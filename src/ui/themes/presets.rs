@@ -1,6 +1,6 @@
 // Theme presets for TUI configuration
 
-use crate::config::{Config, StyleConfig, StyleMode};
+use crate::config::{Config, RecorderConfig, StyleConfig, StyleMode, TimeConfig};
 
 // Import all theme modules
 use super::{
@@ -139,8 +139,23 @@ impl ThemePresets {
                 theme_cometix::cost_segment(),
                 theme_cometix::session_segment(),
                 theme_cometix::output_style_segment(),
+                theme_cometix::time_segment(),
+                theme_cometix::burn_rate_segment(),
+                theme_cometix::spend_segment(),
+                theme_cometix::cache_hit_segment(),
+                theme_cometix::compaction_segment(),
+                theme_cometix::tool_activity_segment(),
+                theme_cometix::todos_segment(),
+                theme_cometix::subagents_segment(),
+                theme_cometix::files_touched_segment(),
+                theme_cometix::turn_stats_segment(),
+                theme_cometix::thinking_segment(),
+                theme_cometix::claude_version_segment(),
+                theme_cometix::session_id_segment(),
             ],
             theme: "cometix".to_string(),
+            time: TimeConfig::default(),
+            recorder: RecorderConfig::default(),
         }
     }
 
@@ -161,8 +176,23 @@ impl ThemePresets {
                 theme_default::cost_segment(),
                 theme_default::session_segment(),
                 theme_default::output_style_segment(),
+                theme_default::time_segment(),
+                theme_default::burn_rate_segment(),
+                theme_default::spend_segment(),
+                theme_default::cache_hit_segment(),
+                theme_default::compaction_segment(),
+                theme_default::tool_activity_segment(),
+                theme_default::todos_segment(),
+                theme_default::subagents_segment(),
+                theme_default::files_touched_segment(),
+                theme_default::turn_stats_segment(),
+                theme_default::thinking_segment(),
+                theme_default::claude_version_segment(),
+                theme_default::session_id_segment(),
             ],
             theme: "default".to_string(),
+            time: TimeConfig::default(),
+            recorder: RecorderConfig::default(),
         }
     }
 
@@ -183,8 +213,23 @@ impl ThemePresets {
                 theme_minimal::cost_segment(),
                 theme_minimal::session_segment(),
                 theme_minimal::output_style_segment(),
+                theme_minimal::time_segment(),
+                theme_minimal::burn_rate_segment(),
+                theme_minimal::spend_segment(),
+                theme_minimal::cache_hit_segment(),
+                theme_minimal::compaction_segment(),
+                theme_minimal::tool_activity_segment(),
+                theme_minimal::todos_segment(),
+                theme_minimal::subagents_segment(),
+                theme_minimal::files_touched_segment(),
+                theme_minimal::turn_stats_segment(),
+                theme_minimal::thinking_segment(),
+                theme_minimal::claude_version_segment(),
+                theme_minimal::session_id_segment(),
             ],
             theme: "minimal".to_string(),
+            time: TimeConfig::default(),
+            recorder: RecorderConfig::default(),
         }
     }
 
@@ -205,8 +250,23 @@ impl ThemePresets {
                 theme_gruvbox::cost_segment(),
                 theme_gruvbox::session_segment(),
                 theme_gruvbox::output_style_segment(),
+                theme_gruvbox::time_segment(),
+                theme_gruvbox::burn_rate_segment(),
+                theme_gruvbox::spend_segment(),
+                theme_gruvbox::cache_hit_segment(),
+                theme_gruvbox::compaction_segment(),
+                theme_gruvbox::tool_activity_segment(),
+                theme_gruvbox::todos_segment(),
+                theme_gruvbox::subagents_segment(),
+                theme_gruvbox::files_touched_segment(),
+                theme_gruvbox::turn_stats_segment(),
+                theme_gruvbox::thinking_segment(),
+                theme_gruvbox::claude_version_segment(),
+                theme_gruvbox::session_id_segment(),
             ],
             theme: "gruvbox".to_string(),
+            time: TimeConfig::default(),
+            recorder: RecorderConfig::default(),
         }
     }
 
@@ -227,8 +287,23 @@ impl ThemePresets {
                 theme_nord::cost_segment(),
                 theme_nord::session_segment(),
                 theme_nord::output_style_segment(),
+                theme_nord::time_segment(),
+                theme_nord::burn_rate_segment(),
+                theme_nord::spend_segment(),
+                theme_nord::cache_hit_segment(),
+                theme_nord::compaction_segment(),
+                theme_nord::tool_activity_segment(),
+                theme_nord::todos_segment(),
+                theme_nord::subagents_segment(),
+                theme_nord::files_touched_segment(),
+                theme_nord::turn_stats_segment(),
+                theme_nord::thinking_segment(),
+                theme_nord::claude_version_segment(),
+                theme_nord::session_id_segment(),
             ],
             theme: "nord".to_string(),
+            time: TimeConfig::default(),
+            recorder: RecorderConfig::default(),
         }
     }
 
@@ -249,8 +324,23 @@ impl ThemePresets {
                 theme_powerline_dark::cost_segment(),
                 theme_powerline_dark::session_segment(),
                 theme_powerline_dark::output_style_segment(),
+                theme_powerline_dark::time_segment(),
+                theme_powerline_dark::burn_rate_segment(),
+                theme_powerline_dark::spend_segment(),
+                theme_powerline_dark::cache_hit_segment(),
+                theme_powerline_dark::compaction_segment(),
+                theme_powerline_dark::tool_activity_segment(),
+                theme_powerline_dark::todos_segment(),
+                theme_powerline_dark::subagents_segment(),
+                theme_powerline_dark::files_touched_segment(),
+                theme_powerline_dark::turn_stats_segment(),
+                theme_powerline_dark::thinking_segment(),
+                theme_powerline_dark::claude_version_segment(),
+                theme_powerline_dark::session_id_segment(),
             ],
             theme: "powerline-dark".to_string(),
+            time: TimeConfig::default(),
+            recorder: RecorderConfig::default(),
         }
     }
 
@@ -271,8 +361,23 @@ impl ThemePresets {
                 theme_powerline_light::cost_segment(),
                 theme_powerline_light::session_segment(),
                 theme_powerline_light::output_style_segment(),
+                theme_powerline_light::time_segment(),
+                theme_powerline_light::burn_rate_segment(),
+                theme_powerline_light::spend_segment(),
+                theme_powerline_light::cache_hit_segment(),
+                theme_powerline_light::compaction_segment(),
+                theme_powerline_light::tool_activity_segment(),
+                theme_powerline_light::todos_segment(),
+                theme_powerline_light::subagents_segment(),
+                theme_powerline_light::files_touched_segment(),
+                theme_powerline_light::turn_stats_segment(),
+                theme_powerline_light::thinking_segment(),
+                theme_powerline_light::claude_version_segment(),
+                theme_powerline_light::session_id_segment(),
             ],
             theme: "powerline-light".to_string(),
+            time: TimeConfig::default(),
+            recorder: RecorderConfig::default(),
         }
     }
 
@@ -293,8 +398,23 @@ impl ThemePresets {
                 theme_powerline_rose_pine::cost_segment(),
                 theme_powerline_rose_pine::session_segment(),
                 theme_powerline_rose_pine::output_style_segment(),
+                theme_powerline_rose_pine::time_segment(),
+                theme_powerline_rose_pine::burn_rate_segment(),
+                theme_powerline_rose_pine::spend_segment(),
+                theme_powerline_rose_pine::cache_hit_segment(),
+                theme_powerline_rose_pine::compaction_segment(),
+                theme_powerline_rose_pine::tool_activity_segment(),
+                theme_powerline_rose_pine::todos_segment(),
+                theme_powerline_rose_pine::subagents_segment(),
+                theme_powerline_rose_pine::files_touched_segment(),
+                theme_powerline_rose_pine::turn_stats_segment(),
+                theme_powerline_rose_pine::thinking_segment(),
+                theme_powerline_rose_pine::claude_version_segment(),
+                theme_powerline_rose_pine::session_id_segment(),
             ],
             theme: "powerline-rose-pine".to_string(),
+            time: TimeConfig::default(),
+            recorder: RecorderConfig::default(),
         }
     }
 
@@ -315,8 +435,23 @@ impl ThemePresets {
                 theme_powerline_tokyo_night::cost_segment(),
                 theme_powerline_tokyo_night::session_segment(),
                 theme_powerline_tokyo_night::output_style_segment(),
+                theme_powerline_tokyo_night::time_segment(),
+                theme_powerline_tokyo_night::burn_rate_segment(),
+                theme_powerline_tokyo_night::spend_segment(),
+                theme_powerline_tokyo_night::cache_hit_segment(),
+                theme_powerline_tokyo_night::compaction_segment(),
+                theme_powerline_tokyo_night::tool_activity_segment(),
+                theme_powerline_tokyo_night::todos_segment(),
+                theme_powerline_tokyo_night::subagents_segment(),
+                theme_powerline_tokyo_night::files_touched_segment(),
+                theme_powerline_tokyo_night::turn_stats_segment(),
+                theme_powerline_tokyo_night::thinking_segment(),
+                theme_powerline_tokyo_night::claude_version_segment(),
+                theme_powerline_tokyo_night::session_id_segment(),
             ],
             theme: "powerline-tokyo-night".to_string(),
+            time: TimeConfig::default(),
+            recorder: RecorderConfig::default(),
         }
     }
 }
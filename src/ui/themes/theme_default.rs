@@ -17,7 +17,27 @@ pub fn model_segment() -> SegmentConfig {
             background: None,
         },
         styles: TextStyleConfig::default(),
-        options: HashMap::new(),
+        options: {
+            let mut opts = HashMap::new();
+            opts.insert("show_served".to_string(), serde_json::Value::Bool(true));
+            opts.insert(
+                "warn_on_mismatch".to_string(),
+                serde_json::Value::Bool(true),
+            );
+            opts.insert(
+                "show_turn_counts".to_string(),
+                serde_json::Value::Bool(false),
+            );
+            opts.insert(
+                "warning_color".to_string(),
+                serde_json::json!({"c16": 11}),
+            );
+            opts.insert(
+                "warning_bold".to_string(),
+                serde_json::Value::Bool(false),
+            );
+            opts
+        },
     }
 }
 
@@ -35,7 +55,23 @@ pub fn directory_segment() -> SegmentConfig {
             background: None,
         },
         styles: TextStyleConfig::default(),
-        options: HashMap::new(),
+        options: {
+            let mut opts = HashMap::new();
+            opts.insert("show_project".to_string(), serde_json::Value::Bool(true));
+            opts.insert(
+                "warn_outside_project".to_string(),
+                serde_json::Value::Bool(true),
+            );
+            opts.insert(
+                "warning_color".to_string(),
+                serde_json::json!({"c16": 11}),
+            );
+            opts.insert(
+                "warning_bold".to_string(),
+                serde_json::Value::Bool(true),
+            );
+            opts
+        },
     }
 }
 
@@ -102,6 +138,30 @@ pub fn context_window_segment() -> SegmentConfig {
                 "critical_bold".to_string(),
                 serde_json::Value::Bool(true),
             );
+            opts.insert(
+                "include_output".to_string(),
+                serde_json::Value::Bool(true),
+            );
+            opts.insert(
+                "relative_to".to_string(),
+                serde_json::Value::String("limit".to_string()),
+            );
+            opts.insert(
+                "show_forecast".to_string(),
+                serde_json::Value::Bool(false),
+            );
+            opts.insert(
+                "progress_bar".to_string(),
+                serde_json::Value::Bool(false),
+            );
+            opts.insert(
+                "bar_width".to_string(),
+                serde_json::Value::Number(10.into()),
+            );
+            opts.insert(
+                "bar_style".to_string(),
+                serde_json::Value::String("blocks".to_string()),
+            );
             opts
         },
     }
@@ -132,6 +192,10 @@ pub fn usage_segment() -> SegmentConfig {
                 serde_json::Value::Number(180.into()),
             );
             opts.insert("timeout".to_string(), serde_json::Value::Number(2.into()));
+            opts.insert(
+                "reset_format".to_string(),
+                serde_json::Value::String("absolute".to_string()),
+            );
             opts
         },
     }
@@ -177,6 +241,38 @@ pub fn usage_5hour_segment() -> SegmentConfig {
                 "critical_bold".to_string(),
                 serde_json::Value::Bool(true),
             );
+            opts.insert(
+                "reset_format".to_string(),
+                serde_json::Value::String("absolute".to_string()),
+            );
+            opts.insert(
+                "source".to_string(),
+                serde_json::Value::String("auto".to_string()),
+            );
+            opts.insert(
+                "token_limit".to_string(),
+                serde_json::Value::Number(0.into()),
+            );
+            opts.insert(
+                "show_projection".to_string(),
+                serde_json::Value::Bool(false),
+            );
+            opts.insert(
+                "progress_bar".to_string(),
+                serde_json::Value::Bool(false),
+            );
+            opts.insert(
+                "bar_width".to_string(),
+                serde_json::Value::Number(10.into()),
+            );
+            opts.insert(
+                "bar_style".to_string(),
+                serde_json::Value::String("blocks".to_string()),
+            );
+            opts.insert(
+                "local_cache_duration".to_string(),
+                serde_json::Value::Number(60.into()),
+            );
             opts
         },
     }
@@ -222,6 +318,22 @@ pub fn usage_7day_segment() -> SegmentConfig {
                 "critical_bold".to_string(),
                 serde_json::Value::Bool(true),
             );
+            opts.insert(
+                "reset_format".to_string(),
+                serde_json::Value::String("absolute".to_string()),
+            );
+            opts.insert(
+                "progress_bar".to_string(),
+                serde_json::Value::Bool(false),
+            );
+            opts.insert(
+                "bar_width".to_string(),
+                serde_json::Value::Number(10.into()),
+            );
+            opts.insert(
+                "bar_style".to_string(),
+                serde_json::Value::String("blocks".to_string()),
+            );
             opts
         },
     }
@@ -241,7 +353,43 @@ pub fn cost_segment() -> SegmentConfig {
             background: None,
         },
         styles: TextStyleConfig::default(),
-        options: HashMap::new(),
+        options: {
+            let mut opts = HashMap::new();
+            opts.insert(
+                "source".to_string(),
+                serde_json::Value::String("auto".to_string()),
+            );
+            opts.insert("budget_usd".to_string(), serde_json::json!(0.0));
+            opts.insert(
+                "show_remaining".to_string(),
+                serde_json::Value::Bool(false),
+            );
+            opts.insert(
+                "warning_threshold".to_string(),
+                serde_json::Value::Number(60.into()),
+            );
+            opts.insert(
+                "critical_threshold".to_string(),
+                serde_json::Value::Number(80.into()),
+            );
+            opts.insert(
+                "warning_color".to_string(),
+                serde_json::json!({"c16": 11}),
+            );
+            opts.insert(
+                "critical_color".to_string(),
+                serde_json::json!({"c16": 9}),
+            );
+            opts.insert(
+                "warning_bold".to_string(),
+                serde_json::Value::Bool(false),
+            );
+            opts.insert(
+                "critical_bold".to_string(),
+                serde_json::Value::Bool(true),
+            );
+            opts
+        },
     }
 }
 
@@ -280,3 +428,452 @@ pub fn output_style_segment() -> SegmentConfig {
         options: HashMap::new(),
     }
 }
+
+pub fn time_segment() -> SegmentConfig {
+    SegmentConfig {
+        id: SegmentId::Time,
+        enabled: false,
+        icon: IconConfig {
+            plain: "🕐".to_string(),
+            nerd_font: "\u{f017}".to_string(),
+        },
+        colors: ColorConfig {
+            icon: Some(AnsiColor::Color16 { c16: 2 }), // Green
+            text: Some(AnsiColor::Color16 { c16: 2 }),
+            background: None,
+        },
+        styles: TextStyleConfig::default(),
+        options: {
+            let mut opts = HashMap::new();
+            opts.insert(
+                "format".to_string(),
+                serde_json::Value::String(String::new()),
+            );
+            opts.insert(
+                "timezone".to_string(),
+                serde_json::Value::String(String::new()),
+            );
+            opts
+        },
+    }
+}
+
+pub fn burn_rate_segment() -> SegmentConfig {
+    SegmentConfig {
+        id: SegmentId::BurnRate,
+        enabled: false,
+        icon: IconConfig {
+            plain: "🔥".to_string(),
+            nerd_font: "\u{f0238}".to_string(),
+        },
+        colors: ColorConfig {
+            icon: Some(AnsiColor::Color16 { c16: 13 }), // Magenta
+            text: Some(AnsiColor::Color16 { c16: 13 }),
+            background: None,
+        },
+        styles: TextStyleConfig::default(),
+        options: {
+            let mut opts = HashMap::new();
+            opts.insert(
+                "window_minutes".to_string(),
+                serde_json::Value::Number(10.into()),
+            );
+            opts.insert(
+                "include_cache_reads".to_string(),
+                serde_json::Value::Bool(false),
+            );
+            opts.insert(
+                "warning_threshold".to_string(),
+                serde_json::Value::Number(5000.into()),
+            );
+            opts.insert(
+                "critical_threshold".to_string(),
+                serde_json::Value::Number(10000.into()),
+            );
+            opts.insert(
+                "warning_color".to_string(),
+                serde_json::json!({"c16": 11}),
+            );
+            opts.insert(
+                "critical_color".to_string(),
+                serde_json::json!({"c16": 9}),
+            );
+            opts.insert(
+                "warning_bold".to_string(),
+                serde_json::Value::Bool(false),
+            );
+            opts.insert(
+                "critical_bold".to_string(),
+                serde_json::Value::Bool(true),
+            );
+            opts
+        },
+    }
+}
+
+pub fn spend_segment() -> SegmentConfig {
+    SegmentConfig {
+        id: SegmentId::Spend,
+        enabled: false,
+        icon: IconConfig {
+            plain: "💵".to_string(),
+            nerd_font: "\u{f0d6}".to_string(),
+        },
+        colors: ColorConfig {
+            icon: Some(AnsiColor::Color16 { c16: 3 }), // Yellow
+            text: Some(AnsiColor::Color16 { c16: 3 }),
+            background: None,
+        },
+        styles: TextStyleConfig::default(),
+        options: {
+            let mut opts = HashMap::new();
+            opts.insert("show_week".to_string(), serde_json::Value::Bool(true));
+            opts.insert("show_month".to_string(), serde_json::Value::Bool(false));
+            opts.insert(
+                "cache_duration".to_string(),
+                serde_json::Value::Number(60.into()),
+            );
+            opts.insert("budget_usd".to_string(), serde_json::json!(0.0));
+            opts.insert(
+                "show_remaining".to_string(),
+                serde_json::Value::Bool(false),
+            );
+            opts.insert(
+                "warning_threshold".to_string(),
+                serde_json::Value::Number(60.into()),
+            );
+            opts.insert(
+                "critical_threshold".to_string(),
+                serde_json::Value::Number(80.into()),
+            );
+            opts.insert(
+                "warning_color".to_string(),
+                serde_json::json!({"c16": 11}),
+            );
+            opts.insert(
+                "critical_color".to_string(),
+                serde_json::json!({"c16": 9}),
+            );
+            opts.insert(
+                "warning_bold".to_string(),
+                serde_json::Value::Bool(false),
+            );
+            opts.insert(
+                "critical_bold".to_string(),
+                serde_json::Value::Bool(true),
+            );
+            opts
+        },
+    }
+}
+
+pub fn cache_hit_segment() -> SegmentConfig {
+    SegmentConfig {
+        id: SegmentId::CacheHit,
+        enabled: false,
+        icon: IconConfig {
+            plain: "♻️".to_string(),
+            nerd_font: "\u{f0487}".to_string(),
+        },
+        colors: ColorConfig {
+            icon: Some(AnsiColor::Color16 { c16: 13 }), // Magenta
+            text: Some(AnsiColor::Color16 { c16: 13 }),
+            background: None,
+        },
+        styles: TextStyleConfig::default(),
+        options: {
+            let mut opts = HashMap::new();
+            opts.insert("show_session".to_string(), serde_json::Value::Bool(true));
+            opts.insert("show_savings".to_string(), serde_json::Value::Bool(true));
+            opts.insert(
+                "warning_threshold".to_string(),
+                serde_json::Value::Number(50.into()),
+            );
+            opts.insert(
+                "critical_threshold".to_string(),
+                serde_json::Value::Number(80.into()),
+            );
+            opts.insert(
+                "warning_color".to_string(),
+                serde_json::json!({"c16": 11}),
+            );
+            opts.insert(
+                "critical_color".to_string(),
+                serde_json::json!({"c16": 9}),
+            );
+            opts.insert(
+                "warning_bold".to_string(),
+                serde_json::Value::Bool(false),
+            );
+            opts.insert(
+                "critical_bold".to_string(),
+                serde_json::Value::Bool(true),
+            );
+            opts
+        },
+    }
+}
+
+pub fn compaction_segment() -> SegmentConfig {
+    SegmentConfig {
+        id: SegmentId::Compaction,
+        enabled: false,
+        icon: IconConfig {
+            plain: "⇊".to_string(),
+            nerd_font: "󰁃".to_string(),
+        },
+        colors: ColorConfig {
+            icon: Some(AnsiColor::Color16 { c16: 13 }), // Magenta
+            text: Some(AnsiColor::Color16 { c16: 13 }),
+            background: None,
+        },
+        styles: TextStyleConfig::default(),
+        options: {
+            let mut opts = HashMap::new();
+            opts.insert(
+                "warning_interval_minutes".to_string(),
+                serde_json::Value::Number(60.into()),
+            );
+            opts.insert(
+                "critical_interval_minutes".to_string(),
+                serde_json::Value::Number(20.into()),
+            );
+            opts.insert(
+                "warning_color".to_string(),
+                serde_json::json!({"c16": 11}),
+            );
+            opts.insert(
+                "critical_color".to_string(),
+                serde_json::json!({"c16": 9}),
+            );
+            opts.insert(
+                "warning_bold".to_string(),
+                serde_json::Value::Bool(false),
+            );
+            opts.insert(
+                "critical_bold".to_string(),
+                serde_json::Value::Bool(true),
+            );
+            opts
+        },
+    }
+}
+
+pub fn tool_activity_segment() -> SegmentConfig {
+    SegmentConfig {
+        id: SegmentId::ToolActivity,
+        enabled: false,
+        icon: IconConfig {
+            plain: "⚒".to_string(),
+            nerd_font: "".to_string(),
+        },
+        colors: ColorConfig {
+            icon: Some(AnsiColor::Color16 { c16: 13 }), // Magenta
+            text: Some(AnsiColor::Color16 { c16: 13 }),
+            background: None,
+        },
+        styles: TextStyleConfig::default(),
+        options: {
+            let mut opts = HashMap::new();
+            opts.insert("show_last".to_string(), serde_json::Value::Bool(true));
+            opts.insert("show_total".to_string(), serde_json::Value::Bool(true));
+            opts.insert("show_by_tool".to_string(), serde_json::Value::Bool(true));
+            opts.insert("show_errors".to_string(), serde_json::Value::Bool(true));
+            opts.insert(
+                "top_tools".to_string(),
+                serde_json::Value::Number(3.into()),
+            );
+            opts.insert(
+                "max_target_length".to_string(),
+                serde_json::Value::Number(30.into()),
+            );
+            opts
+        },
+    }
+}
+
+pub fn todos_segment() -> SegmentConfig {
+    SegmentConfig {
+        id: SegmentId::Todos,
+        enabled: false,
+        icon: IconConfig {
+            plain: "☑".to_string(),
+            nerd_font: "".to_string(),
+        },
+        colors: ColorConfig {
+            icon: Some(AnsiColor::Color16 { c16: 13 }), // Magenta
+            text: Some(AnsiColor::Color16 { c16: 13 }),
+            background: None,
+        },
+        styles: TextStyleConfig::default(),
+        options: {
+            let mut opts = HashMap::new();
+            opts.insert("show_current".to_string(), serde_json::Value::Bool(true));
+            opts.insert(
+                "max_text_length".to_string(),
+                serde_json::Value::Number(40.into()),
+            );
+            opts.insert(
+                "progress_bar".to_string(),
+                serde_json::Value::Bool(false),
+            );
+            opts.insert(
+                "bar_width".to_string(),
+                serde_json::Value::Number(10.into()),
+            );
+            opts.insert(
+                "bar_style".to_string(),
+                serde_json::Value::String("blocks".to_string()),
+            );
+            opts
+        },
+    }
+}
+
+pub fn subagents_segment() -> SegmentConfig {
+    SegmentConfig {
+        id: SegmentId::Subagents,
+        enabled: false,
+        icon: IconConfig {
+            plain: "⇉".to_string(),
+            nerd_font: "󰚩".to_string(),
+        },
+        colors: ColorConfig {
+            icon: Some(AnsiColor::Color16 { c16: 13 }), // Magenta
+            text: Some(AnsiColor::Color16 { c16: 13 }),
+            background: None,
+        },
+        styles: TextStyleConfig::default(),
+        options: {
+            let mut opts = HashMap::new();
+            opts.insert(
+                "show_descriptions".to_string(),
+                serde_json::Value::Bool(true),
+            );
+            opts.insert("show_tokens".to_string(), serde_json::Value::Bool(true));
+            opts.insert(
+                "hide_when_idle".to_string(),
+                serde_json::Value::Bool(false),
+            );
+            opts.insert(
+                "max_text_length".to_string(),
+                serde_json::Value::Number(30.into()),
+            );
+            opts
+        },
+    }
+}
+
+pub fn files_touched_segment() -> SegmentConfig {
+    SegmentConfig {
+        id: SegmentId::FilesTouched,
+        enabled: false,
+        icon: IconConfig {
+            plain: "✎".to_string(),
+            nerd_font: "".to_string(),
+        },
+        colors: ColorConfig {
+            icon: Some(AnsiColor::Color16 { c16: 13 }), // Magenta
+            text: Some(AnsiColor::Color16 { c16: 13 }),
+            background: None,
+        },
+        styles: TextStyleConfig::default(),
+        options: {
+            let mut opts = HashMap::new();
+            opts.insert("show_last".to_string(), serde_json::Value::Bool(true));
+            opts
+        },
+    }
+}
+
+pub fn turn_stats_segment() -> SegmentConfig {
+    SegmentConfig {
+        id: SegmentId::TurnStats,
+        enabled: false,
+        icon: IconConfig {
+            plain: "⇅".to_string(),
+            nerd_font: "󰸛".to_string(),
+        },
+        colors: ColorConfig {
+            icon: Some(AnsiColor::Color16 { c16: 13 }), // Magenta
+            text: Some(AnsiColor::Color16 { c16: 13 }),
+            background: None,
+        },
+        styles: TextStyleConfig::default(),
+        options: {
+            let mut opts = HashMap::new();
+            opts.insert(
+                "format".to_string(),
+                serde_json::Value::String(
+                    "{prompts} · {avg_latency}/turn · {since_prompt} ago".to_string(),
+                ),
+            );
+            opts
+        },
+    }
+}
+
+pub fn thinking_segment() -> SegmentConfig {
+    SegmentConfig {
+        id: SegmentId::Thinking,
+        enabled: false,
+        icon: IconConfig {
+            plain: "💭".to_string(),
+            nerd_font: "󰌵".to_string(),
+        },
+        colors: ColorConfig {
+            icon: Some(AnsiColor::Color16 { c16: 13 }), // Magenta
+            text: Some(AnsiColor::Color16 { c16: 13 }),
+            background: None,
+        },
+        styles: TextStyleConfig::default(),
+        options: {
+            let mut opts = HashMap::new();
+            opts.insert(
+                "hide_when_off".to_string(),
+                serde_json::Value::Bool(false),
+            );
+            opts
+        },
+    }
+}
+
+pub fn claude_version_segment() -> SegmentConfig {
+    SegmentConfig {
+        id: SegmentId::ClaudeVersion,
+        enabled: false,
+        icon: IconConfig {
+            plain: "✱".to_string(),
+            nerd_font: "".to_string(),
+        },
+        colors: ColorConfig {
+            icon: Some(AnsiColor::Color16 { c16: 13 }), // Magenta
+            text: Some(AnsiColor::Color16 { c16: 13 }),
+            background: None,
+        },
+        styles: TextStyleConfig::default(),
+        options: HashMap::new(),
+    }
+}
+
+pub fn session_id_segment() -> SegmentConfig {
+    SegmentConfig {
+        id: SegmentId::SessionId,
+        enabled: false,
+        icon: IconConfig {
+            plain: "#".to_string(),
+            nerd_font: "".to_string(),
+        },
+        colors: ColorConfig {
+            icon: Some(AnsiColor::Color16 { c16: 13 }), // Magenta
+            text: Some(AnsiColor::Color16 { c16: 13 }),
+            background: None,
+        },
+        styles: TextStyleConfig::default(),
+        options: {
+            let mut opts = HashMap::new();
+            opts.insert("length".to_string(), serde_json::Value::Number(8.into()));
+            opts
+        },
+    }
+}
@@ -1,4 +1,7 @@
 pub mod claude_code_patcher;
 pub mod credentials;
+pub mod text;
+pub mod time_format;
 
 pub use claude_code_patcher::{ClaudeCodePatcher, LocationResult};
+pub use time_format::TimeFormatter;
@@ -0,0 +1,8 @@
+/// Shorten to `max_chars` characters, marking the cut with an ellipsis
+pub fn truncate(text: &str, max_chars: usize) -> String {
+    if text.chars().count() <= max_chars {
+        return text.to_string();
+    }
+    let kept: String = text.chars().take(max_chars.saturating_sub(1)).collect();
+    format!("{}…", kept)
+}
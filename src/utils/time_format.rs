@@ -0,0 +1,108 @@
+use crate::config::{HourFormat, TimeConfig};
+use chrono::{DateTime, Duration, Local, Locale, TimeZone, Timelike};
+use std::fmt::Display;
+
+/// Shared date/time formatter honoring the global 12h/24h and locale preferences
+#[derive(Debug, Clone, Copy)]
+pub struct TimeFormatter {
+    hour_format: HourFormat,
+    locale: Locale,
+}
+
+impl Default for TimeFormatter {
+    fn default() -> Self {
+        Self::new(&TimeConfig::default())
+    }
+}
+
+impl TimeFormatter {
+    pub fn new(config: &TimeConfig) -> Self {
+        // Accept both "zh_CN" and "zh-CN" spellings, fall back to en_US when unknown
+        let locale =
+            Locale::try_from(config.locale.replace('-', "_").as_str()).unwrap_or(Locale::en_US);
+
+        Self {
+            hour_format: config.hour_format,
+            locale,
+        }
+    }
+
+    pub fn hour_format(&self) -> HourFormat {
+        self.hour_format
+    }
+
+    /// Format a datetime with a strftime-style pattern using the configured locale
+    pub fn format<Tz: TimeZone>(&self, dt: &DateTime<Tz>, pattern: &str) -> String
+    where
+        Tz::Offset: Display,
+    {
+        dt.format_localized(pattern, self.locale).to_string()
+    }
+
+    /// Default clock pattern: "2:05pm" or "14:05"
+    pub fn time_pattern(&self) -> &'static str {
+        match self.hour_format {
+            HourFormat::TwelveHour => "%-I:%M%P",
+            HourFormat::TwentyFourHour => "%H:%M",
+        }
+    }
+
+    /// Format an hour-rounded time as "5pm" or "17:00"
+    pub fn format_hour<Tz: TimeZone>(&self, dt: &DateTime<Tz>) -> String
+    where
+        Tz::Offset: Display,
+    {
+        match self.hour_format {
+            HourFormat::TwelveHour => self.format(dt, "%-I%P"),
+            HourFormat::TwentyFourHour => self.format(dt, "%H:00"),
+        }
+    }
+
+    /// Format an hour-rounded date as "Oct 9, 5am" or "Oct 9, 05:00"
+    pub fn format_date_hour<Tz: TimeZone>(&self, dt: &DateTime<Tz>) -> String
+    where
+        Tz::Offset: Display,
+    {
+        format!("{}, {}", self.format(dt, "%b %-d"), self.format_hour(dt))
+    }
+
+    /// Format a remaining duration as "45m", "2h13m" or "3d4h"
+    /// Day-level countdowns drop minutes using the same >45 minute round-up rule
+    pub fn format_countdown(remaining: Duration) -> String {
+        let total_minutes = remaining.num_minutes().max(0);
+        let (hours, minutes) = (total_minutes / 60, total_minutes % 60);
+
+        if hours == 0 {
+            format!("{}m", minutes)
+        } else if hours < 24 {
+            if minutes == 0 {
+                format!("{}h", hours)
+            } else {
+                format!("{}h{}m", hours, minutes)
+            }
+        } else {
+            let hours = if minutes > 45 { hours + 1 } else { hours };
+            let (days, hours) = (hours / 24, hours % 24);
+            if hours == 0 {
+                format!("{}d", days)
+            } else {
+                format!("{}d{}h", days, hours)
+            }
+        }
+    }
+
+    /// Parse an RFC 3339 timestamp into local time
+    pub fn parse_local(time_str: Option<&str>) -> Option<DateTime<Local>> {
+        let dt = DateTime::parse_from_rfc3339(time_str?).ok()?;
+        Some(dt.with_timezone(&Local))
+    }
+
+    /// Round up to the next hour if more than 45 minutes past the hour
+    pub fn round_to_hour(dt: DateTime<Local>) -> DateTime<Local> {
+        if dt.minute() > 45 {
+            dt + Duration::hours(1)
+        } else {
+            dt
+        }
+    }
+}